@@ -1,178 +1,326 @@
 extern crate test;
 
+use super::Adjacency;
 use super::AdjacencyList;
+use super::Edge;
 use super::load_adj_list_from_file;
+use super::ShortestPaths;
+use super::Weight;
+use super::visitor::{Color, Control, Visitor};
+
+use crate::heap::{BinaryMinHeap, HeapElement, HeapMethod};
 
 use std::collections::VecDeque;
 use std::collections::BinaryHeap;
+use std::collections::BTreeMap;
 
 use std::cmp::Ordering;
 
 use std::fmt;
+use std::marker::PhantomData;
+
 
+/// `A` is the representation of graph, e.g. `AdjacencyList` which can be mutated
+/// or `CompressedSparseRow` which is frozen but faster to traverse
+pub struct Graph<W = i32, A = AdjacencyList<W>> {
+    adj_list: A,
+    _weight: PhantomData<W>,
+}
 
-pub struct Graph {
-    adj_list: AdjacencyList,
+/// priority queue used by dijkstra
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DijkstraHeap {
+    /// `std::collections::BinaryHeap` with lazy deletion,
+    /// may hold up to `num_edges` stale elements
+    Lazy,
+    /// `heap::BinaryMinHeap` with decrease key, holds at most `num_vertices` elements
+    Indexed,
 }
 
 /// ways to create graph
-impl Graph {
-    pub fn new(adj_list: AdjacencyList) -> Graph {
+impl<W: Weight, A: Adjacency<W>> Graph<W, A> {
+    pub fn new(adj_list: A) -> Graph<W, A> {
         Graph{
-            adj_list
+            adj_list,
+            _weight: PhantomData,
         }
     }
 
-    pub fn create_from_file(file_path: &str) -> Graph {
+    pub fn adj_list(&self) -> &A {
+        &self.adj_list
+    }
+}
+
+impl<W: Weight> Graph<W> {
+    pub fn create_from_file(file_path: &str) -> Graph<W> {
         let adj_list = load_adj_list_from_file(file_path);
-        Graph {
-            adj_list
-        }
+        Graph::new(adj_list)
+    }
+
+    /// mutate the graph in place, e.g. add or remove vertices and edges
+    pub fn adj_list_mut(&mut self) -> &mut AdjacencyList<W> {
+        &mut self.adj_list
     }
 }
 
 /// graph alogorithms
-impl Graph {
-    pub fn dfs(&self) {
-        let mut visited = vec![false; self.adj_list.num_vertices()+1];
-        let mut stack = Vec::with_capacity(self.adj_list.num_vertices());
+impl<W: Weight, A: Adjacency<W>> Graph<W, A> {
+    /// depth first search over all vertices, a new search tree is started
+    /// from every vertex not discovered yet in ascending order
+    pub fn dfs(&self, visitor: &mut dyn Visitor<W>) -> Control {
+        let mut color = vec![Color::White; self.adj_list.num_vertices()+1];
 
-        for i in 1..visited.len() {
-            if !visited[i] {
-                visited[i] = true;
-                stack.push(i);
+        for vertex in 1..color.len() {
+            if color[vertex] == Color::White {
+                stop_if_asked!(self.dfs_visit(vertex, &mut color, visitor));
             }
-            while !stack.is_empty() {
-                let vertex = stack.pop().unwrap();
-                // do something before visited all adjacent points
-                // println!("visited vertex: {}", vertex);
-                let edge_iterator = self.adj_list.edge_iterator_of_vertex(vertex);
-                for edge in edge_iterator {
-                    let current_vertex = edge.out_vertex;
-                    if !visited[current_vertex] {
-                        visited[current_vertex] = true;
-                        stack.push(current_vertex);
-                    }
+        }
+
+        Control::Continue
+    }
+
+    /// depth first search over vertices reachable from `vertex`
+    pub fn dfs_from(&self, vertex: usize, visitor: &mut dyn Visitor<W>) -> Control {
+        let mut color = vec![Color::White; self.adj_list.num_vertices()+1];
+        self.dfs_visit(vertex, &mut color, visitor)
+    }
+
+    fn dfs_visit(&self, root: usize, color: &mut [Color], visitor: &mut dyn Visitor<W>) -> Control {
+        stop_if_asked!(visitor.start_vertex(root));
+        color[root] = Color::Gray;
+        stop_if_asked!(visitor.discover_vertex(root));
+
+        // each vertex on stack keeps the iterator over its remaining edges
+        let mut stack = vec![(root, self.adj_list.indexed_edge_iterator_of_vertex(root))];
+
+        while let Some((vertex, edge_iterator)) = stack.last_mut() {
+            let vertex = *vertex;
+            let (edge_index, edge) = match edge_iterator.next() {
+                Some(x) => x,
+                None => {
+                    stack.pop();
+                    color[vertex] = Color::Black;
+                    stop_if_asked!(visitor.finish_vertex(vertex));
+                    continue;
                 }
+            };
+
+            stop_if_asked!(visitor.examine_edge(edge_index, &edge));
+            match color[edge.out_vertex] {
+                Color::White => {
+                    stop_if_asked!(visitor.tree_edge(edge_index, &edge));
+                    color[edge.out_vertex] = Color::Gray;
+                    stop_if_asked!(visitor.discover_vertex(edge.out_vertex));
+                    stack.push((edge.out_vertex, self.adj_list.indexed_edge_iterator_of_vertex(edge.out_vertex)));
+                },
+                Color::Gray => stop_if_asked!(visitor.back_edge(edge_index, &edge)),
+                Color::Black => stop_if_asked!(visitor.forward_or_cross_edge(edge_index, &edge)),
             }
-            // do something when after visited all adjacent points
         }
+
+        Control::Continue
     }
 
-    pub fn bfs(&self) {
-        let mut visited = vec![false; self.adj_list.num_vertices()+1];
+    /// breadth first search over all vertices, a new search tree is started
+    /// from every vertex not discovered yet in ascending order
+    pub fn bfs(&self, visitor: &mut dyn Visitor<W>) -> Control {
+        let mut color = vec![Color::White; self.adj_list.num_vertices()+1];
         let mut queue: VecDeque<usize> = VecDeque::with_capacity(self.adj_list.num_vertices());
-        
-        for i in 1..visited.len() {
-            if !visited[i] {
-                visited[i] = true;
-                queue.push_back(i);
+
+        for vertex in 1..color.len() {
+            if color[vertex] == Color::White {
+                stop_if_asked!(self.bfs_visit(vertex, &mut color, &mut queue, visitor));
             }
-            while !queue.is_empty() {
-                let vertex = queue.pop_front().unwrap();
-                // do something before visited all adjacent point
-                // println!("vertex: {}", vertex);
-                let edge_iterator = self.adj_list.edge_iterator_of_vertex(vertex);
-                for edge in edge_iterator {
-                    let current_vertex = edge.out_vertex;
-                    if !visited[current_vertex] {
-                        visited[current_vertex] = true;
-                        queue.push_back(current_vertex);
-                    }
+        }
+
+        Control::Continue
+    }
+
+    /// breadth first search over vertices reachable from `vertex`
+    pub fn bfs_from(&self, vertex: usize, visitor: &mut dyn Visitor<W>) -> Control {
+        let mut color = vec![Color::White; self.adj_list.num_vertices()+1];
+        let mut queue: VecDeque<usize> = VecDeque::with_capacity(self.adj_list.num_vertices());
+        self.bfs_visit(vertex, &mut color, &mut queue, visitor)
+    }
+
+    fn bfs_visit(
+        &self,
+        root: usize,
+        color: &mut [Color],
+        queue: &mut VecDeque<usize>,
+        visitor: &mut dyn Visitor<W>,
+    ) -> Control {
+        stop_if_asked!(visitor.start_vertex(root));
+        color[root] = Color::Gray;
+        stop_if_asked!(visitor.discover_vertex(root));
+        queue.push_back(root);
+
+        while let Some(vertex) = queue.pop_front() {
+            for (edge_index, edge) in self.adj_list.indexed_edge_iterator_of_vertex(vertex) {
+                stop_if_asked!(visitor.examine_edge(edge_index, &edge));
+                match color[edge.out_vertex] {
+                    Color::White => {
+                        stop_if_asked!(visitor.tree_edge(edge_index, &edge));
+                        color[edge.out_vertex] = Color::Gray;
+                        stop_if_asked!(visitor.discover_vertex(edge.out_vertex));
+                        queue.push_back(edge.out_vertex);
+                    },
+                    _ => stop_if_asked!(visitor.non_tree_edge(edge_index, &edge)),
                 }
             }
-            // do something when after visited all adjacent points
+            color[vertex] = Color::Black;
+            stop_if_asked!(visitor.finish_vertex(vertex));
         }
+
+        Control::Continue
     }
 
-    pub fn dijkstra(&self, vertex: usize) {
+    /// single source shortest paths, weight of all edges must be non-negative
+    pub fn dijkstra(&self, vertex: usize) -> ShortestPaths<W> {
+        self.dijkstra_with_heap(vertex, DijkstraHeap::Indexed)
+    }
+
+    pub fn dijkstra_with_heap(&self, vertex: usize, heap: DijkstraHeap) -> ShortestPaths<W> {
+        match heap {
+            DijkstraHeap::Lazy => self.lazy_dijkstra(vertex),
+            DijkstraHeap::Indexed => self.indexed_dijkstra(vertex),
+        }
+    }
+
+    fn lazy_dijkstra(&self, vertex: usize) -> ShortestPaths<W> {
         // used for heap
-        struct Edge {
+        struct Edge<W> {
             vertex: usize,
-            weight: i32,
+            weight: W,
         }
 
-        impl PartialEq for Edge {
+        impl<W: Weight> PartialEq for Edge<W> {
             fn eq(&self, other: &Self) -> bool {
                 self.weight == other.weight
             }
         }
 
-        impl Eq for Edge {}
+        impl<W: Weight> Eq for Edge<W> {}
 
-        impl PartialOrd for Edge {
+        impl<W: Weight> PartialOrd for Edge<W> {
             fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
                 Some(self.cmp(other))
             }
         }
 
-        impl Ord for Edge {
+        impl<W: Weight> Ord for Edge<W> {
             fn cmp(&self, other: &Self) -> Ordering {
                 // flipping the ordering on weight because heap in standard library is max-heap
                 other.weight.cmp(&self.weight)
             }
         }
 
-        impl fmt::Display for Edge {
+        impl<W: Weight> fmt::Display for Edge<W> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
                 write!(f, "vertex: {}, weight: {}", self.vertex, self.weight)
             }
         }
         
-        // todo
+        // skip stale elements when popped instead of decreasing key
         // https://stackoverflow.com/questions/14252582/how-can-i-use-binary-heap-in-the-dijkstra-algorithm
-        // maybe we need heap with map
-        // solution below require weight be unique, this is surely not applicable to our case
-        // https://stackoverflow.com/questions/17009056/how-to-implement-ologn-decrease-key-operation-for-min-heap-based-priority-queu
-        // or just skip but need more allocate
-        let mut heap: BinaryHeap<Edge> = BinaryHeap::with_capacity(self.adj_list.num_edges());
+        let mut heap: BinaryHeap<Edge<W>> = BinaryHeap::with_capacity(self.adj_list.num_edges());
 
         // the shortest distance of certain vertex has already been found or not
         let mut visited = vec![false; self.adj_list.num_vertices()+1];
-        // the shortest distance from origin vertex to another
-        // here we use -1 indicate infinity
-        // todo
-        // note that i32 may be exceed
-        let mut distance: Vec<i32> = vec![-1; self.adj_list.num_vertices()+1];
+        // the shortest distance from origin vertex to another,
+        // overflow of distance panics instead of wrapping silently
+        let mut shortest_paths = ShortestPaths::new(vertex, self.adj_list.num_vertices());
 
         // initialize with origin vertex
-        // boundary check may affect performance
-        visited[vertex] = true;
-        distance[vertex] = 0;
-        
-        for edge in self.adj_list.edge_iterator_of_vertex(vertex) {
-            distance[edge.out_vertex] = edge.weight;
-            heap.push(Edge{vertex: edge.out_vertex, weight: edge.weight});
-        }
+        heap.push(Edge{vertex, weight: W::zero()});
 
         while let Some(Edge{vertex, weight}) = heap.pop() {
             if visited[vertex] {continue;}
             visited[vertex] = true;
-            distance[vertex] = weight;
 
-            for edge in self.adj_list.edge_iterator_of_vertex(vertex) {
+            for (edge_index, edge) in self.adj_list.indexed_edge_iterator_of_vertex(vertex) {
                 if visited[edge.out_vertex] {continue;}
-                
-                let new_weight = weight + edge.weight;
 
-                match distance[edge.out_vertex] {
-                    -1 => {
+                let new_weight = weight.add_or_panic(edge.weight);
+
+                match shortest_paths.distance(edge.out_vertex) {
+                    Some(x) if x <= new_weight => {},
+                    _ => {
                         heap.push(Edge{vertex: edge.out_vertex, weight: new_weight});
-                        distance[edge.out_vertex] = new_weight;
+                        shortest_paths.update(edge.out_vertex, new_weight, vertex, edge_index);
+                    }
+                }
+            }
+        }
+
+        shortest_paths
+    }
+
+    fn indexed_dijkstra(&self, vertex: usize) -> ShortestPaths<W> {
+        self.reweighted_dijkstra(vertex, |edge| edge.weight)
+    }
+
+    /// indexed dijkstra with weight of each edge given by `weight`,
+    /// used by algorithms that reweight edges to be non-negative
+    pub(super) fn reweighted_dijkstra<F>(&self, vertex: usize, weight: F) -> ShortestPaths<W>
+    where
+        F: Fn(&Edge<W>) -> W,
+    {
+        // key is the distance and track_id is the vertex,
+        // every vertex is in heap at most once
+        let mut heap: BinaryMinHeap<W, usize> = BinaryMinHeap::with_capacity(self.adj_list.num_vertices());
+
+        let mut visited = vec![false; self.adj_list.num_vertices()+1];
+        let mut shortest_paths = ShortestPaths::new(vertex, self.adj_list.num_vertices());
+
+        heap.push(HeapElement::new(W::zero(), vertex));
+
+        while let Some(element) = heap.pop() {
+            let vertex = element.track_id();
+            let distance = *element.key();
+            visited[vertex] = true;
+
+            for (edge_index, edge) in self.adj_list.indexed_edge_iterator_of_vertex(vertex) {
+                if visited[edge.out_vertex] {continue;}
+
+                let new_weight = distance.add_or_panic(weight(&edge));
+
+                match shortest_paths.distance(edge.out_vertex) {
+                    Some(x) if x <= new_weight => {},
+                    Some(_) => {
+                        heap.decrease_key(new_weight, edge.out_vertex);
+                        shortest_paths.update(edge.out_vertex, new_weight, vertex, edge_index);
                     },
-                    x => {
-                        
-                        if new_weight < x {
-                            heap.push(Edge{vertex: edge.out_vertex, weight: new_weight});
-                            distance[edge.out_vertex] = new_weight;
-                        }
+                    None => {
+                        heap.push(HeapElement::new(new_weight, edge.out_vertex));
+                        shortest_paths.update(edge.out_vertex, new_weight, vertex, edge_index);
                     }
                 }
             }
         }
 
-        // println!("{:?}", distance);
-        // println!("{:?}", visited);
+        shortest_paths
+    }
+
+    /// pair every edge with an edge of the same weight from its out vertex to its in vertex,
+    /// which are the two directions of an undirected edge, `None` if there is no such edge.
+    /// index of edges is not relied on since edges may be removed or stored in any order
+    pub(super) fn twins(&self) -> Vec<Option<usize>> {
+        let mut twins = vec![None; self.adj_list.num_edges() + 1];
+        // edges waiting for a twin, keyed by (in vertex, out vertex, weight)
+        let mut unpaired: BTreeMap<(usize, usize, W), Vec<usize>> = BTreeMap::new();
+        for vertex in 1..=self.adj_list.num_vertices() {
+            for (edge_index, edge) in self.adj_list.indexed_edge_iterator_of_vertex(vertex) {
+                match unpaired.get_mut(&(edge.out_vertex, edge.in_vertex, edge.weight)).and_then(|x| x.pop()) {
+                    Some(twin) => {
+                        twins[edge_index] = Some(twin);
+                        twins[twin] = Some(edge_index);
+                    },
+                    None => unpaired.entry((edge.in_vertex, edge.out_vertex, edge.weight)).or_default().push(edge_index),
+                }
+            }
+        }
+        twins
     }
 }
 
@@ -180,6 +328,8 @@ impl Graph {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::csr::load_csr_from_file;
+    use crate::graph::weight::TotalF64;
     use test::Bencher;
     
     const TEST_GRAPH_FILE: &str = "src/graph/examples/test.txt";
@@ -192,31 +342,222 @@ mod tests {
     const POSITIVE_COMPLICATED_GRAPH_FILE: &str = "src/graph/examples/positive_complicated.txt";
     // const POSITIVE_DENSE_GRAPH_FILE: &str = "src/graph/examples/positive_dense.txt";
 
+    /// record events as strings
+    #[derive(Default)]
+    struct Recorder {
+        events: Vec<String>,
+        stop_at: Option<usize>,
+    }
+
+    impl Visitor for Recorder {
+        fn start_vertex(&mut self, vertex: usize) -> Control {
+            self.events.push(format!("start {}", vertex));
+            Control::Continue
+        }
+
+        fn discover_vertex(&mut self, vertex: usize) -> Control {
+            self.events.push(format!("discover {}", vertex));
+            match self.stop_at {
+                Some(x) if x == vertex => Control::Stop,
+                _ => Control::Continue,
+            }
+        }
+
+        fn tree_edge(&mut self, _edge_index: usize, edge: &Edge) -> Control {
+            self.events.push(format!("tree {}->{}", edge.in_vertex(), edge.out_vertex()));
+            Control::Continue
+        }
+
+        fn back_edge(&mut self, _edge_index: usize, edge: &Edge) -> Control {
+            self.events.push(format!("back {}->{}", edge.in_vertex(), edge.out_vertex()));
+            Control::Continue
+        }
+
+        fn forward_or_cross_edge(&mut self, _edge_index: usize, edge: &Edge) -> Control {
+            self.events.push(format!("cross {}->{}", edge.in_vertex(), edge.out_vertex()));
+            Control::Continue
+        }
+
+        fn non_tree_edge(&mut self, _edge_index: usize, edge: &Edge) -> Control {
+            self.events.push(format!("non tree {}->{}", edge.in_vertex(), edge.out_vertex()));
+            Control::Continue
+        }
+
+        fn finish_vertex(&mut self, vertex: usize) -> Control {
+            self.events.push(format!("finish {}", vertex));
+            Control::Continue
+        }
+    }
+
     #[test]
     fn test_graph_dfs() {
         let graph = Graph::create_from_file(TEST_GRAPH_FILE);
-        graph.dfs();
+        let mut recorder = Recorder::default();
+        assert_eq!(graph.dfs(&mut recorder), Control::Continue);
+        assert_eq!(recorder.events, vec![
+            "start 1", "discover 1",
+            "tree 1->4", "discover 4", "finish 4",
+            "tree 1->2", "discover 2",
+            "cross 2->4",
+            "tree 2->3", "discover 3",
+            "cross 3->4",
+            "finish 3", "finish 2", "finish 1",
+        ]);
+
+        let mut adj_list = AdjacencyList::new(3, 3);
+        adj_list.add_directed_edge(1, 2, 1);
+        adj_list.add_directed_edge(2, 3, 1);
+        adj_list.add_directed_edge(3, 2, 1);
+        let graph = Graph::new(adj_list);
+        let mut recorder = Recorder::default();
+        graph.dfs_from(2, &mut recorder);
+        assert_eq!(recorder.events, vec![
+            "start 2", "discover 2", "tree 2->3", "discover 3", "back 3->2", "finish 3", "finish 2",
+        ]);
+        let mut recorder = Recorder::default();
+        graph.dfs(&mut recorder);
+        assert_eq!(recorder.events[..2], ["start 1", "discover 1"]);
     }
-    
+
+    #[test]
+    fn test_graph_dfs_stop() {
+        let graph = Graph::create_from_file(TEST_GRAPH_FILE);
+        let mut recorder = Recorder { stop_at: Some(2), ..Recorder::default() };
+        assert_eq!(graph.dfs(&mut recorder), Control::Stop);
+        assert_eq!(recorder.events.last().unwrap(), "discover 2");
+    }
+
     #[test]
     fn test_graph_bfs() {
         let graph = Graph::create_from_file(TEST_GRAPH_FILE);
-        graph.bfs();
+        let mut recorder = Recorder::default();
+        assert_eq!(graph.bfs(&mut recorder), Control::Continue);
+        assert_eq!(recorder.events, vec![
+            "start 1", "discover 1",
+            "tree 1->4", "discover 4",
+            "tree 1->2", "discover 2",
+            "finish 1", "finish 4",
+            "non tree 2->4",
+            "tree 2->3", "discover 3",
+            "finish 2",
+            "non tree 3->4",
+            "finish 3",
+        ]);
+
+        let mut recorder = Recorder { stop_at: Some(4), ..Recorder::default() };
+        assert_eq!(graph.bfs_from(1, &mut recorder), Control::Stop);
+        assert_eq!(recorder.events.last().unwrap(), "discover 4");
     }
 
     #[test]
     fn test_graph_dijkstra() {
         let graph = Graph::create_from_file(POSITIVE_TEST_GRAPH_FILE);
-        graph.dijkstra(4);
+        let shortest_paths = graph.dijkstra(4);
+
+        assert_eq!(
+            shortest_paths.distances()[1..],
+            [Some(22), Some(13), Some(3), Some(0), Some(4), Some(6), Some(12)]
+        );
+
+        let path = shortest_paths.path_to(1).unwrap();
+        assert_eq!(path.vertices(), &[4, 5, 6, 1]);
+        assert_eq!(path.cost(), 22);
+        for (i, &edge_index) in path.edges().iter().enumerate() {
+            let edge = graph.adj_list().edge(edge_index);
+            assert_eq!((edge.in_vertex(), edge.out_vertex()), (path.vertices()[i], path.vertices()[i + 1]));
+        }
+        assert_eq!(shortest_paths.path_to(4).unwrap().vertices(), &[4]);
+    }
+
+    #[test]
+    fn test_graph_dijkstra_heaps() {
+        for file in [POSITIVE_TEST_GRAPH_FILE, POSITIVE_SIMPLE_GRAPH_FILE].iter() {
+            let graph: Graph = Graph::create_from_file(file);
+            let lazy = graph.dijkstra_with_heap(1, DijkstraHeap::Lazy);
+            let indexed = graph.dijkstra_with_heap(1, DijkstraHeap::Indexed);
+            assert_eq!(lazy.distances(), indexed.distances());
+        }
+    }
+
+    #[test]
+    fn test_graph_dijkstra_unreachable() {
+        let mut adj_list = AdjacencyList::new(3, 1);
+        adj_list.add_directed_edge(1, 2, 5);
+        let graph = Graph::new(adj_list);
+        let shortest_paths = graph.dijkstra(1);
+
+        assert_eq!(shortest_paths.distance(2), Some(5));
+        assert!(!shortest_paths.is_reachable(3));
+        assert!(shortest_paths.path_to(3).is_none());
+    }
+
+    #[test]
+    fn test_graph_dijkstra_generic_weight() {
+        // the path 1 -> 2 -> 3 is longer than `i32::MAX`
+        let mut adj_list: AdjacencyList<i64> = AdjacencyList::new(3, 3);
+        adj_list.add_directed_edge(1, 2, 2_000_000_000);
+        adj_list.add_directed_edge(2, 3, 2_000_000_000);
+        adj_list.add_directed_edge(1, 3, 5_000_000_000);
+        let shortest_paths = Graph::new(adj_list).dijkstra(1);
+        assert_eq!(shortest_paths.distance(3), Some(4_000_000_000));
+
+        let graph = Graph::<u64>::create_from_file(POSITIVE_TEST_GRAPH_FILE);
+        let distances: Vec<Option<u64>> = vec![None, Some(22), Some(13), Some(3), Some(0), Some(4), Some(6), Some(12)];
+        assert_eq!(graph.dijkstra(4).distances(), distances.as_slice());
+        assert_eq!(graph.dijkstra_with_heap(4, DijkstraHeap::Lazy).distances(), distances.as_slice());
+
+        let mut adj_list = AdjacencyList::new(3, 3);
+        adj_list.add_directed_edge(1, 2, TotalF64(0.5));
+        adj_list.add_directed_edge(2, 3, TotalF64(0.25));
+        adj_list.add_directed_edge(1, 3, TotalF64(1.0));
+        let path = Graph::new(adj_list).dijkstra(1).path_to(3).unwrap();
+        assert_eq!(path.vertices(), &[1, 2, 3]);
+        assert_eq!(path.cost(), TotalF64(0.75));
+    }
+
+    #[test]
+    #[should_panic(expected = "weight overflows")]
+    fn test_graph_dijkstra_overflow() {
+        let mut adj_list = AdjacencyList::new(3, 2);
+        adj_list.add_directed_edge(1, 2, i32::MAX);
+        adj_list.add_directed_edge(2, 3, 1);
+        Graph::new(adj_list).dijkstra(1);
     }
 
     #[bench]
     fn bench_graph_dijkstra(b: &mut Bencher) {
-        let graph = Graph::create_from_file(POSITIVE_COMPLICATED_GRAPH_FILE);
+        let graph: Graph = Graph::create_from_file(POSITIVE_COMPLICATED_GRAPH_FILE);
         // let graph = Graph::create_from_file(POSITIVE_DENSE_GRAPH_FILE);
 
         b.iter(|| {
             graph.dijkstra(1);
         });
     }
+
+    #[bench]
+    fn bench_graph_lazy_dijkstra(b: &mut Bencher) {
+        let graph: Graph = Graph::create_from_file(POSITIVE_SIMPLE_GRAPH_FILE);
+
+        b.iter(|| {
+            graph.dijkstra_with_heap(1, DijkstraHeap::Lazy);
+        });
+    }
+
+    #[bench]
+    fn bench_graph_indexed_dijkstra(b: &mut Bencher) {
+        let graph: Graph = Graph::create_from_file(POSITIVE_SIMPLE_GRAPH_FILE);
+
+        b.iter(|| {
+            graph.dijkstra_with_heap(1, DijkstraHeap::Indexed);
+        });
+    }
+
+    #[bench]
+    fn bench_graph_csr_dijkstra(b: &mut Bencher) {
+        let graph = Graph::new(load_csr_from_file::<i32>(POSITIVE_SIMPLE_GRAPH_FILE));
+
+        b.iter(|| {
+            graph.dijkstra(1);
+        });
+    }
 }
\ No newline at end of file
@@ -0,0 +1,260 @@
+use super::algorithms::Graph;
+use super::shortest_path::{NegativeCycle, Path};
+use super::{Adjacency, Weight};
+
+/// result of all pairs shortest path algorithms
+/// index of vertex is started from 1, so row 0 and column 0 are never used
+#[derive(Debug)]
+pub struct DistanceMatrix<W = i32> {
+    /// `distance[source][target]`, `None` means target can not be reached from source
+    distance: Vec<Vec<Option<W>>>,
+    /// index of the last edge on the shortest path from source to target
+    predecessor_edge: Vec<Vec<Option<usize>>>,
+    /// the vertex that last edge comes from
+    predecessor_vertex: Vec<Vec<Option<usize>>>,
+}
+
+impl<W: Weight> DistanceMatrix<W> {
+    fn new(num_vertices: usize) -> DistanceMatrix<W> {
+        let mut distance = vec![vec![None; num_vertices + 1]; num_vertices + 1];
+        for (vertex, row) in distance.iter_mut().enumerate().skip(1) {
+            row[vertex] = Some(W::zero());
+        }
+
+        DistanceMatrix {
+            distance,
+            predecessor_edge: vec![vec![None; num_vertices + 1]; num_vertices + 1],
+            predecessor_vertex: vec![vec![None; num_vertices + 1]; num_vertices + 1],
+        }
+    }
+
+    pub fn num_vertices(&self) -> usize {
+        self.distance.len() - 1
+    }
+
+    pub fn distance(&self, source: usize, target: usize) -> Option<W> {
+        self.distance[source][target]
+    }
+
+    /// distances from `source` to all vertices
+    pub fn distances_from(&self, source: usize) -> &[Option<W>] {
+        &self.distance[source]
+    }
+
+    /// index of the last edge on the shortest path from `source` to `target`
+    pub fn predecessor(&self, source: usize, target: usize) -> Option<usize> {
+        self.predecessor_edge[source][target]
+    }
+
+    /// rebuild the shortest path from `source` to `target`
+    pub fn path(&self, source: usize, target: usize) -> Option<Path<W>> {
+        let cost = self.distance[source][target]?;
+
+        let mut vertices = vec![target];
+        let mut edges = Vec::new();
+        let mut current_vertex = target;
+        while current_vertex != source {
+            edges.push(self.predecessor_edge[source][current_vertex].unwrap());
+            current_vertex = self.predecessor_vertex[source][current_vertex].unwrap();
+            vertices.push(current_vertex);
+        }
+        vertices.reverse();
+        edges.reverse();
+
+        Some(Path::new(vertices, edges, cost))
+    }
+}
+
+
+/// all pairs shortest paths
+impl<W: Weight, A: Adjacency<W>> Graph<W, A> {
+    /// [Floyd-Warshall](https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm),
+    /// O(V^3) time and O(V^2) memory, suitable for small dense graphs
+    pub fn floyd_warshall(&self) -> Result<DistanceMatrix<W>, NegativeCycle> {
+        let adj_list = self.adj_list();
+        let num_vertices = adj_list.num_vertices();
+        let mut matrix = DistanceMatrix::new(num_vertices);
+
+        for in_vertex in 1..=num_vertices {
+            for (edge_index, edge) in adj_list.indexed_edge_iterator_of_vertex(in_vertex) {
+                let distance = &mut matrix.distance[in_vertex][edge.out_vertex];
+                match *distance {
+                    Some(x) if x <= edge.weight => {},
+                    _ => {
+                        *distance = Some(edge.weight);
+                        matrix.predecessor_edge[in_vertex][edge.out_vertex] = Some(edge_index);
+                        matrix.predecessor_vertex[in_vertex][edge.out_vertex] = Some(in_vertex);
+                    }
+                }
+            }
+        }
+
+        for k in 1..=num_vertices {
+            for i in 1..=num_vertices {
+                let distance_ik = match matrix.distance[i][k] {
+                    Some(x) => x,
+                    None => continue,
+                };
+
+                for j in 1..=num_vertices {
+                    let distance_kj = match matrix.distance[k][j] {
+                        Some(x) => x,
+                        None => continue,
+                    };
+
+                    let new_distance = distance_ik.add_or_panic(distance_kj);
+                    match matrix.distance[i][j] {
+                        Some(x) if x <= new_distance => {},
+                        _ => {
+                            matrix.distance[i][j] = Some(new_distance);
+                            matrix.predecessor_edge[i][j] = matrix.predecessor_edge[k][j];
+                            matrix.predecessor_vertex[i][j] = matrix.predecessor_vertex[k][j];
+                        }
+                    }
+                }
+
+                // vertex on a negative cycle, stop before distances keep decreasing
+                if matrix.distance[i][i].unwrap() < W::zero() {
+                    return Err(self.bellman_ford(i).unwrap_err());
+                }
+            }
+        }
+
+        Ok(matrix)
+    }
+
+    /// [Johnson](https://en.wikipedia.org/wiki/Johnson%27s_algorithm),
+    /// reweight edges with bellman ford then run dijkstra from every vertex,
+    /// O(VE log V) time, suitable for sparse graphs with negative weights.
+    /// panics if a reweighted edge or a reweighted distance does not fit in `W`,
+    /// which only happens when weights are close to both ends of the range of `W`
+    pub fn johnson(&self) -> Result<DistanceMatrix<W>, NegativeCycle> {
+        let num_vertices = self.adj_list().num_vertices();
+        let potential = self.potential()?;
+        let mut matrix = DistanceMatrix::new(num_vertices);
+
+        for source in 1..=num_vertices {
+            let shortest_paths = self.reweighted_dijkstra(source, |edge| {
+                reweight(edge.weight, potential[edge.in_vertex], potential[edge.out_vertex])
+            });
+
+            for target in 1..=num_vertices {
+                matrix.distance[source][target] = shortest_paths
+                    .distance(target)
+                    .map(|x| reweight(x, potential[target], potential[source]));
+                matrix.predecessor_edge[source][target] = shortest_paths.predecessor(target);
+                matrix.predecessor_vertex[source][target] = shortest_paths.predecessor_vertex(target);
+            }
+        }
+
+        Ok(matrix)
+    }
+}
+
+
+/// `weight + add - sub` where `add` and `sub` are potentials, which are never positive,
+/// the potential with the opposite sign of `weight` is applied first so that
+/// only a result which does not fit in `W` overflows
+fn reweight<W: Weight>(weight: W, add: W, sub: W) -> W {
+    if weight >= W::zero() {
+        weight.add_or_panic(add).sub_or_panic(sub)
+    } else {
+        weight.sub_or_panic(sub).add_or_panic(add)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::AdjacencyList;
+
+    const TEST_GRAPH_FILE: &str = "src/graph/examples/test.txt";
+    const POSITIVE_TEST_GRAPH_FILE: &str = "src/graph/examples/positive_test.txt";
+
+    fn assert_same_as_bellman_ford(graph: &Graph, matrix: &DistanceMatrix) {
+        for source in 1..=graph.adj_list().num_vertices() {
+            let shortest_paths = graph.bellman_ford(source).unwrap();
+            assert_eq!(matrix.distances_from(source)[1..], shortest_paths.distances()[1..]);
+
+            for target in 1..=graph.adj_list().num_vertices() {
+                if let Some(path) = matrix.path(source, target) {
+                    let cost: i32 = path.edges().iter().map(|&x| graph.adj_list().edge(x).weight()).sum();
+                    assert_eq!(cost, path.cost());
+                    assert_eq!(path.vertices().first(), Some(&source));
+                    assert_eq!(path.vertices().last(), Some(&target));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_floyd_warshall() {
+        for file in [TEST_GRAPH_FILE, POSITIVE_TEST_GRAPH_FILE].iter() {
+            let graph = Graph::create_from_file(file);
+            let matrix = graph.floyd_warshall().unwrap();
+            assert_same_as_bellman_ford(&graph, &matrix);
+        }
+
+        let graph = Graph::create_from_file(TEST_GRAPH_FILE);
+        let matrix = graph.floyd_warshall().unwrap();
+        assert_eq!(matrix.distance(1, 4), Some(7));
+        assert_eq!(matrix.distance(4, 1), None);
+        assert_eq!(matrix.path(1, 4).unwrap().vertices(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_johnson() {
+        for file in [TEST_GRAPH_FILE, POSITIVE_TEST_GRAPH_FILE].iter() {
+            let graph = Graph::create_from_file(file);
+            let matrix = graph.johnson().unwrap();
+            assert_same_as_bellman_ford(&graph, &matrix);
+        }
+    }
+
+    #[test]
+    fn test_johnson_large_potential() {
+        // potential of 1 and 2 is -2e9, adding it to 5e8 before subtracting would overflow
+        let mut adj_list = AdjacencyList::new(3, 3);
+        adj_list.add_directed_edge(3, 1, -2_000_000_000);
+        adj_list.add_directed_edge(3, 2, -2_000_000_000);
+        adj_list.add_directed_edge(1, 2, 500_000_000);
+        let graph = Graph::new(adj_list);
+
+        let matrix = graph.johnson().unwrap();
+        assert_eq!(matrix.distance(1, 2), Some(500_000_000));
+        assert_eq!(matrix.distance(3, 2), Some(-2_000_000_000));
+        assert_eq!(matrix.distance(2, 1), None);
+    }
+
+    #[test]
+    fn test_johnson_extreme_weights() {
+        // potential of 1 is i32::MIN and potential of 3 is 0, their difference does not fit in i32
+        let mut adj_list = AdjacencyList::new(3, 2);
+        adj_list.add_directed_edge(3, 1, i32::MIN);
+        adj_list.add_directed_edge(1, 2, i32::MAX);
+        let graph = Graph::new(adj_list);
+
+        let matrix = graph.johnson().unwrap();
+        assert_eq!(matrix.distance(3, 1), Some(i32::MIN));
+        assert_eq!(matrix.distance(1, 2), Some(i32::MAX));
+        assert_eq!(matrix.distance(3, 2), Some(-1));
+        assert_eq!(matrix.distance(2, 3), None);
+    }
+
+    #[test]
+    fn test_all_pairs_negative_cycle() {
+        let mut adj_list = AdjacencyList::new(4, 4);
+        adj_list.add_directed_edge(1, 2, 1);
+        adj_list.add_directed_edge(3, 4, 2);
+        adj_list.add_directed_edge(4, 3, -3);
+        adj_list.add_directed_edge(2, 3, 1);
+        let graph = Graph::new(adj_list);
+
+        for cycle in [graph.floyd_warshall().unwrap_err(), graph.johnson().unwrap_err()].iter() {
+            let mut vertices = cycle.vertices().to_vec();
+            vertices.sort();
+            assert_eq!(vertices, vec![3, 4]);
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use super::algorithms::Graph;
+use super::Adjacency;
+
+use std::collections::HashMap;
+
+/// whether total cost of assignment should be minimized or maximized
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    Minimize,
+    Maximize,
+}
+
+/// result of weighted assignment
+#[derive(Debug)]
+pub struct Assignment {
+    /// `(row, column)` of cost matrix or `(left vertex, right vertex)` of bipartite graph,
+    /// in ascending order of the first element
+    pairs: Vec<(usize, usize)>,
+    /// accumulated in `i64` since the sum of several costs easily exceeds `i32`
+    cost: i64,
+}
+
+impl Assignment {
+    pub fn size(&self) -> usize {
+        self.pairs.len()
+    }
+
+    pub fn pairs(&self) -> &[(usize, usize)] {
+        &self.pairs
+    }
+
+    pub fn cost(&self) -> i64 {
+        self.cost
+    }
+}
+
+
+/// [Hungarian algorithm](https://en.wikipedia.org/wiki/Hungarian_algorithm) on a dense cost matrix,
+/// `costs[i][j]` is the cost of assigning row `i` to column `j`, both index are started from 0.
+/// rectangular matrix is allowed, then every row or every column is assigned
+pub fn hungarian(costs: &[Vec<i32>], objective: Objective) -> Assignment {
+    let num_columns = costs.first().map_or(0, |x| x.len());
+    if costs.iter().any(|x| x.len() != num_columns) {
+        panic!("all rows of cost matrix must have the same length!");
+    }
+
+    let costs: Vec<Vec<Option<i32>>> = costs
+        .iter()
+        .map(|row| row.iter().map(|&x| Some(x)).collect())
+        .collect();
+    let pairs = assign(&costs, num_columns, objective);
+
+    Assignment {
+        cost: pairs.iter().map(|&(i, j)| costs[i][j].unwrap() as i64).sum(),
+        pairs,
+    }
+}
+
+/// O(n²m) where n is the smaller side, `None` means row can not be assigned to column,
+/// assignments with fewer forbidden cells are always preferred and forbidden cells are never returned
+fn assign(costs: &[Vec<Option<i32>>], num_columns: usize, objective: Objective) -> Vec<(usize, usize)> {
+    let sign = match objective {
+        Objective::Minimize => 1,
+        Objective::Maximize => -1,
+    };
+    let num_rows = costs.len();
+    let transposed = num_rows > num_columns;
+    let (n, m) = if transposed { (num_columns, num_rows) } else { (num_rows, num_columns) };
+    let cost_of = |i: usize, j: usize| {
+        let cost = if transposed { costs[j][i] } else { costs[i][j] };
+        cost.map(|x| sign * x as i64)
+    };
+
+    // larger than the difference between any two assignments without forbidden cells
+    let max_abs = costs.iter().flatten().flatten().map(|&x| (x as i64).abs()).max().unwrap_or(0);
+    let forbidden = (2 * max_abs + 1) * (n as i64 + 1);
+
+    // potentials of rows and columns, `row_of[j]` is the row assigned to column j,
+    // rows and columns are started from 1 and column 0 is a virtual one
+    let mut u = vec![0i64; n + 1];
+    let mut v = vec![0i64; m + 1];
+    let mut row_of = vec![0usize; m + 1];
+    let mut way = vec![0usize; m + 1];
+
+    for row in 1..=n {
+        row_of[0] = row;
+        let mut column = 0;
+        let mut min_slack = vec![i64::MAX; m + 1];
+        let mut used = vec![false; m + 1];
+
+        // grow alternating tree until a free column is reached
+        loop {
+            used[column] = true;
+            let current_row = row_of[column];
+            let mut delta = i64::MAX;
+            let mut next_column = 0;
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let slack = cost_of(current_row - 1, j - 1).unwrap_or(forbidden) - u[current_row] - v[j];
+                if slack < min_slack[j] {
+                    min_slack[j] = slack;
+                    way[j] = column;
+                }
+                if min_slack[j] < delta {
+                    delta = min_slack[j];
+                    next_column = j;
+                }
+            }
+            for j in 0..=m {
+                if used[j] {
+                    u[row_of[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min_slack[j] -= delta;
+                }
+            }
+            column = next_column;
+            if row_of[column] == 0 {
+                break;
+            }
+        }
+
+        // flip the augmenting path
+        while column != 0 {
+            let previous_column = way[column];
+            row_of[column] = row_of[previous_column];
+            column = previous_column;
+        }
+    }
+
+    let mut pairs: Vec<(usize, usize)> = (1..=m)
+        .filter(|&j| row_of[j] != 0 && cost_of(row_of[j] - 1, j - 1).is_some())
+        .map(|j| if transposed { (j - 1, row_of[j] - 1) } else { (row_of[j] - 1, j - 1) })
+        .collect();
+    pairs.sort();
+    pairs
+}
+
+
+/// weighted assignment on undirected bipartite graphs, e.g. built with `AdjacencyList::add_undirected_edge`
+impl<A: Adjacency<i32>> Graph<i32, A> {
+    /// hungarian algorithm between `left` and the other vertices, e.g. workers and jobs,
+    /// only edges from `left` to the other side are used and missing edges can not be assigned,
+    /// so the number of pairs is maximized first and then the total weight is optimized
+    pub fn hungarian(&self, left: &[usize], objective: Objective) -> Assignment {
+        let num_vertices = self.adj_list().num_vertices();
+        let mut is_left = vec![false; num_vertices + 1];
+        for &vertex in left.iter() {
+            if vertex == 0 || vertex > num_vertices {
+                panic!("vertex with index {} does not exist!", vertex);
+            }
+            is_left[vertex] = true;
+        }
+        let mut left: Vec<usize> = left.to_vec();
+        left.sort_unstable();
+        left.dedup();
+        let right: Vec<usize> = (1..=num_vertices).filter(|&x| !is_left[x]).collect();
+        let right_index: HashMap<usize, usize> = right.iter().enumerate().map(|(i, &x)| (x, i)).collect();
+
+        // only the best one of parallel edges matters
+        let mut costs = vec![vec![None; right.len()]; left.len()];
+        for (i, &vertex) in left.iter().enumerate() {
+            for edge in self.adj_list().edge_iterator_of_vertex(vertex) {
+                let j = match right_index.get(&edge.out_vertex) {
+                    Some(&x) => x,
+                    None => continue,
+                };
+                costs[i][j] = match (costs[i][j], objective) {
+                    (Some(x), Objective::Minimize) => Some(edge.weight.min(x)),
+                    (Some(x), Objective::Maximize) => Some(edge.weight.max(x)),
+                    (None, _) => Some(edge.weight),
+                };
+            }
+        }
+
+        let pairs = assign(&costs, right.len(), objective);
+        Assignment {
+            cost: pairs.iter().map(|&(i, j)| costs[i][j].unwrap() as i64).sum(),
+            pairs: pairs.iter().map(|&(i, j)| (left[i], right[j])).collect(),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::matrix::AdjacencyMatrix;
+    use crate::graph::AdjacencyList;
+
+    use rand::Rng;
+
+    /// try every injection from the smaller side to the larger one
+    fn brute_force(costs: &[Vec<i32>], objective: Objective) -> i64 {
+        fn search(costs: &[Vec<i32>], row: usize, used: &mut Vec<bool>, objective: Objective) -> i64 {
+            if row == costs.len() {
+                return 0;
+            }
+            let mut best = None;
+            for column in 0..used.len() {
+                if used[column] {
+                    continue;
+                }
+                used[column] = true;
+                let cost = costs[row][column] as i64 + search(costs, row + 1, used, objective);
+                used[column] = false;
+                best = match (best, objective) {
+                    (Some(x), Objective::Minimize) => Some(cost.min(x)),
+                    (Some(x), Objective::Maximize) => Some(cost.max(x)),
+                    (None, _) => Some(cost),
+                };
+            }
+            best.unwrap()
+        }
+
+        if costs.len() > costs[0].len() {
+            let transposed: Vec<Vec<i32>> = (0..costs[0].len())
+                .map(|j| costs.iter().map(|row| row[j]).collect())
+                .collect();
+            return brute_force(&transposed, objective);
+        }
+        search(costs, 0, &mut vec![false; costs[0].len()], objective)
+    }
+
+    #[test]
+    fn test_hungarian() {
+        let costs = vec![vec![4, 1, 3], vec![2, 0, 5], vec![3, 2, 2]];
+        let assignment = hungarian(&costs, Objective::Minimize);
+        assert_eq!(assignment.cost(), 5);
+        assert_eq!(assignment.pairs(), &[(0, 1), (1, 0), (2, 2)]);
+
+        let assignment = hungarian(&costs, Objective::Maximize);
+        assert_eq!(assignment.cost(), 11);
+        assert_eq!(assignment.pairs(), &[(0, 0), (1, 2), (2, 1)]);
+
+        assert_eq!(hungarian(&[], Objective::Minimize).size(), 0);
+    }
+
+    #[test]
+    fn test_hungarian_rectangular() {
+        let costs = vec![vec![10, 3, 7, 1], vec![2, 8, 4, 9]];
+        let assignment = hungarian(&costs, Objective::Minimize);
+        assert_eq!(assignment.pairs(), &[(0, 3), (1, 0)]);
+        assert_eq!(assignment.cost(), 3);
+
+        let costs = vec![vec![10, 2], vec![3, 8], vec![7, 4], vec![1, 9]];
+        let assignment = hungarian(&costs, Objective::Maximize);
+        assert_eq!(assignment.pairs(), &[(0, 0), (3, 1)]);
+        assert_eq!(assignment.cost(), 19);
+    }
+
+    #[test]
+    fn test_hungarian_large_cost() {
+        let max = i32::MAX;
+        let costs = vec![vec![max, max - 1, max], vec![max - 2, max, max], vec![max, max, max - 3]];
+        let assignment = hungarian(&costs, Objective::Minimize);
+        assert_eq!(assignment.pairs(), &[(0, 1), (1, 0), (2, 2)]);
+        assert_eq!(assignment.cost(), 3 * max as i64 - 6);
+
+        let costs = vec![vec![i32::MIN, 0], vec![0, i32::MIN]];
+        let assignment = hungarian(&costs, Objective::Minimize);
+        assert_eq!(assignment.cost(), 2 * i32::MIN as i64);
+        let assignment = hungarian(&costs, Objective::Maximize);
+        assert_eq!(assignment.cost(), 0);
+    }
+
+    #[test]
+    fn test_hungarian_same_as_brute_force() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let num_rows = rng.gen_range(1, 6);
+            let num_columns = rng.gen_range(1, 6);
+            let costs: Vec<Vec<i32>> = (0..num_rows)
+                .map(|_| (0..num_columns).map(|_| rng.gen_range(-20, 50)).collect())
+                .collect();
+            for &objective in [Objective::Minimize, Objective::Maximize].iter() {
+                let assignment = hungarian(&costs, objective);
+                assert_eq!(assignment.size(), num_rows.min(num_columns));
+                assert_eq!(assignment.cost(), brute_force(&costs, objective));
+            }
+        }
+    }
+
+    #[test]
+    fn test_hungarian_on_graph() {
+        // workers 1..=3 and jobs 4..=6, not every worker can do every job
+        let mut adj_list = AdjacencyList::new(6, 14);
+        adj_list.add_undirected_edge(1, 4, 3);
+        adj_list.add_undirected_edge(1, 5, 6);
+        adj_list.add_undirected_edge(2, 4, 1);
+        adj_list.add_undirected_edge(2, 5, 5);
+        adj_list.add_undirected_edge(2, 6, 3);
+        adj_list.add_undirected_edge(3, 4, 9);
+        adj_list.add_undirected_edge(3, 6, 1);
+        let graph = Graph::new(adj_list);
+
+        let assignment = graph.hungarian(&[1, 2, 3], Objective::Minimize);
+        assert_eq!(assignment.pairs(), &[(1, 5), (2, 4), (3, 6)]);
+        assert_eq!(assignment.cost(), 8);
+
+        let assignment = graph.hungarian(&[1, 2, 3], Objective::Maximize);
+        assert_eq!(assignment.pairs(), &[(1, 5), (2, 6), (3, 4)]);
+        assert_eq!(assignment.cost(), 18);
+
+        // worker 3 and 4 compete for the only job 5 they can do
+        let mut adj_list = AdjacencyList::new(5, 6);
+        adj_list.add_undirected_edge(1, 2, 4);
+        adj_list.add_undirected_edge(3, 5, 7);
+        adj_list.add_undirected_edge(4, 5, 2);
+        let assignment = Graph::new(adj_list).hungarian(&[1, 3, 4], Objective::Minimize);
+        assert_eq!(assignment.pairs(), &[(1, 2), (4, 5)]);
+        assert_eq!(assignment.cost(), 6);
+    }
+
+    #[test]
+    fn test_hungarian_on_graph_components() {
+        // workers 3 and 4 are larger than their jobs and lie in different components,
+        // an edge between two workers is ignored
+        let mut adj_list = AdjacencyList::new(5, 8);
+        adj_list.add_undirected_edge(3, 1, 2);
+        adj_list.add_undirected_edge(4, 2, 5);
+        adj_list.add_undirected_edge(4, 5, 1);
+        adj_list.add_undirected_edge(3, 4, 0);
+        let graph = Graph::new(adj_list);
+
+        let assignment = graph.hungarian(&[4, 3], Objective::Minimize);
+        assert_eq!(assignment.pairs(), &[(3, 1), (4, 5)]);
+        assert_eq!(assignment.cost(), 3);
+
+        let assignment = graph.hungarian(&[4, 3], Objective::Maximize);
+        assert_eq!(assignment.pairs(), &[(3, 1), (4, 2)]);
+        assert_eq!(assignment.cost(), 7);
+    }
+
+    #[test]
+    fn test_hungarian_on_matrix() {
+        // same graph as the second case of `test_hungarian_on_graph`
+        let mut matrix = AdjacencyMatrix::new(5);
+        matrix.set_undirected_edge(1, 2, 4);
+        matrix.set_undirected_edge(3, 5, 7);
+        matrix.set_undirected_edge(4, 5, 2);
+        let assignment = Graph::new(matrix).hungarian(&[1, 3, 4], Objective::Minimize);
+        assert_eq!(assignment.pairs(), &[(1, 2), (4, 5)]);
+        assert_eq!(assignment.cost(), 6);
+    }
+}
@@ -0,0 +1,264 @@
+use super::algorithms::Graph;
+use super::{Adjacency, Weight};
+
+/// bridges, articulation points and biconnected components of an undirected graph
+/// every undirected edge is represented by the index of one of its two directed edges,
+/// self loops belong to no biconnected component
+#[derive(Debug)]
+pub struct Biconnectivity {
+    bridges: Vec<usize>,
+    articulation_points: Vec<usize>,
+    components: Vec<Vec<usize>>,
+}
+
+impl Biconnectivity {
+    /// edges whose removal disconnects the graph
+    pub fn bridges(&self) -> &[usize] {
+        &self.bridges
+    }
+
+    /// vertices whose removal disconnects the graph, in ascending order
+    pub fn articulation_points(&self) -> &[usize] {
+        &self.articulation_points
+    }
+
+    /// edges of each biconnected component
+    pub fn components(&self) -> &[Vec<usize>] {
+        &self.components
+    }
+}
+
+/// frame of the iterative dfs
+/// `I` iterates over the remaining edges of vertex
+struct Frame<I> {
+    vertex: usize,
+    /// the tree edge through which vertex is discovered and the vertex it comes from
+    parent: Option<(usize, usize)>,
+    /// the twin of the tree edge leads back to parent and must be skipped,
+    /// other parallel edges to parent are real back edges
+    parent_twin: Option<usize>,
+    edge_iterator: I,
+}
+
+
+/// connectivity of undirected graphs built with `AdjacencyList::add_undirected_edge`
+impl<W: Weight, A: Adjacency<W>> Graph<W, A> {
+    /// low link based [biconnectivity](https://en.wikipedia.org/wiki/Biconnected_component)
+    pub fn biconnectivity(&self) -> Biconnectivity {
+        let adj_list = self.adj_list();
+        let num_vertices = adj_list.num_vertices();
+        let twins = self.twins();
+
+        // order in which vertices are discovered, 0 means not discovered yet
+        let mut discovery = vec![0; num_vertices + 1];
+        let mut low_link = vec![0; num_vertices + 1];
+        let mut time = 0;
+
+        let mut bridges = Vec::new();
+        let mut is_articulation_point = vec![false; num_vertices + 1];
+        let mut components = Vec::new();
+        // tree and back edges not assigned to a component yet
+        let mut edge_stack = Vec::new();
+
+        for root in 1..=num_vertices {
+            if discovery[root] != 0 {
+                continue;
+            }
+
+            time += 1;
+            discovery[root] = time;
+            low_link[root] = time;
+            let mut root_children = 0;
+            let mut stack = vec![Frame {
+                vertex: root,
+                parent: None,
+                parent_twin: None,
+                edge_iterator: adj_list.indexed_edge_iterator_of_vertex(root),
+            }];
+
+            while let Some(frame) = stack.last_mut() {
+                let vertex = frame.vertex;
+                match frame.edge_iterator.next() {
+                    Some((edge_index, edge)) => {
+                        let next_vertex = edge.out_vertex;
+                        if frame.parent_twin == Some(edge_index) {
+                            continue;
+                        }
+
+                        if discovery[next_vertex] == 0 {
+                            time += 1;
+                            discovery[next_vertex] = time;
+                            low_link[next_vertex] = time;
+                            edge_stack.push(edge_index);
+                            if vertex == root {
+                                root_children += 1;
+                            }
+                            stack.push(Frame {
+                                vertex: next_vertex,
+                                parent: Some((edge_index, vertex)),
+                                parent_twin: twins[edge_index],
+                                edge_iterator: adj_list.indexed_edge_iterator_of_vertex(next_vertex),
+                            });
+                        } else if discovery[next_vertex] < discovery[vertex] {
+                            // back edge to an ancestor, the same edge seen from the ancestor is ignored
+                            low_link[vertex] = low_link[vertex].min(discovery[next_vertex]);
+                            edge_stack.push(edge_index);
+                        }
+                    },
+                    None => {
+                        let (tree_edge, parent) = match stack.pop().unwrap().parent {
+                            Some(x) => x,
+                            None => continue,
+                        };
+                        low_link[parent] = low_link[parent].min(low_link[vertex]);
+
+                        if low_link[vertex] > discovery[parent] {
+                            bridges.push(tree_edge);
+                        }
+                        // subtree of vertex can not reach above parent
+                        if low_link[vertex] >= discovery[parent] {
+                            if parent != root {
+                                is_articulation_point[parent] = true;
+                            }
+                            let mut component = Vec::new();
+                            while let Some(edge_index) = edge_stack.pop() {
+                                component.push(edge_index);
+                                if edge_index == tree_edge {
+                                    break;
+                                }
+                            }
+                            components.push(component);
+                        }
+                    }
+                }
+            }
+
+            if root_children >= 2 {
+                is_articulation_point[root] = true;
+            }
+        }
+
+        Biconnectivity {
+            bridges,
+            articulation_points: (1..=num_vertices).filter(|&x| is_articulation_point[x]).collect(),
+            components,
+        }
+    }
+
+    pub fn bridges(&self) -> Vec<usize> {
+        self.biconnectivity().bridges
+    }
+
+    pub fn articulation_points(&self) -> Vec<usize> {
+        self.biconnectivity().articulation_points
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::AdjacencyList;
+
+    const POSITIVE_TEST_GRAPH_FILE: &str = "src/graph/examples/positive_test.txt";
+
+    /// two triangles joined by a bridge, with a pendant edge and an isolated vertex
+    fn bowtie_graph() -> Graph {
+        let mut adj_list = AdjacencyList::new(8, 16);
+        adj_list.add_undirected_edge(1, 2, 1);
+        adj_list.add_undirected_edge(2, 3, 1);
+        adj_list.add_undirected_edge(3, 1, 1);
+        adj_list.add_undirected_edge(3, 4, 1);
+        adj_list.add_undirected_edge(4, 5, 1);
+        adj_list.add_undirected_edge(5, 6, 1);
+        adj_list.add_undirected_edge(6, 4, 1);
+        adj_list.add_undirected_edge(6, 7, 1);
+        Graph::new(adj_list)
+    }
+
+    fn endpoints(graph: &Graph, edge_index: usize) -> (usize, usize) {
+        let edge = graph.adj_list().edge(edge_index);
+        (edge.in_vertex().min(edge.out_vertex()), edge.in_vertex().max(edge.out_vertex()))
+    }
+
+    fn component_vertices(graph: &Graph, component: &[usize]) -> Vec<usize> {
+        let mut vertices: Vec<usize> = component
+            .iter()
+            .flat_map(|&x| {
+                let (u, v) = endpoints(graph, x);
+                vec![u, v]
+            })
+            .collect();
+        vertices.sort();
+        vertices.dedup();
+        vertices
+    }
+
+    #[test]
+    fn test_biconnectivity() {
+        let graph = bowtie_graph();
+        let biconnectivity = graph.biconnectivity();
+
+        let mut bridges: Vec<(usize, usize)> = biconnectivity.bridges().iter().map(|&x| endpoints(&graph, x)).collect();
+        bridges.sort();
+        assert_eq!(bridges, vec![(3, 4), (6, 7)]);
+        assert_eq!(biconnectivity.articulation_points(), &[3, 4, 6]);
+
+        let mut components: Vec<Vec<usize>> = biconnectivity
+            .components()
+            .iter()
+            .map(|x| component_vertices(&graph, x))
+            .collect();
+        components.sort();
+        assert_eq!(components, vec![vec![1, 2, 3], vec![3, 4], vec![4, 5, 6], vec![6, 7]]);
+        let num_edges: usize = biconnectivity.components().iter().map(|x| x.len()).sum();
+        assert_eq!(num_edges, 8);
+    }
+
+    #[test]
+    fn test_biconnectivity_parallel_edges() {
+        // the twin of the tree edge 1 -> 2 with weight 2 is not the first edge of vertex 2 back to 1
+        let mut adj_list = AdjacencyList::new(3, 6);
+        adj_list.add_directed_edge(1, 2, 1);
+        adj_list.add_directed_edge(1, 2, 2);
+        adj_list.add_directed_edge(2, 1, 2);
+        adj_list.add_directed_edge(2, 1, 1);
+        adj_list.add_undirected_edge(2, 3, 1);
+        let graph = Graph::new(adj_list);
+        let biconnectivity = graph.biconnectivity();
+
+        let bridges: Vec<(usize, usize)> = biconnectivity.bridges().iter().map(|&x| endpoints(&graph, x)).collect();
+        assert_eq!(bridges, vec![(2, 3)]);
+        assert_eq!(biconnectivity.articulation_points(), &[2]);
+
+        // both parallel edges, each exactly once
+        let mut components: Vec<Vec<(usize, usize, i32)>> = biconnectivity
+            .components()
+            .iter()
+            .map(|component| {
+                let mut edges: Vec<(usize, usize, i32)> = component
+                    .iter()
+                    .map(|&x| {
+                        let (u, v) = endpoints(&graph, x);
+                        (u, v, graph.adj_list().edge(x).weight())
+                    })
+                    .collect();
+                edges.sort();
+                edges
+            })
+            .collect();
+        components.sort();
+        assert_eq!(components, vec![vec![(1, 2, 1), (1, 2, 2)], vec![(2, 3, 1)]]);
+    }
+
+    #[test]
+    fn test_biconnectivity_file() {
+        // loaded from file, each undirected edge is given as two lines
+        let graph: Graph = Graph::create_from_file(POSITIVE_TEST_GRAPH_FILE);
+        let biconnectivity = graph.biconnectivity();
+
+        assert!(biconnectivity.bridges().is_empty());
+        assert!(biconnectivity.articulation_points().is_empty());
+        assert_eq!(biconnectivity.components().len(), 1);
+    }
+}
@@ -0,0 +1,396 @@
+use super::algorithms::Graph;
+use super::visitor::{Control, Visitor};
+use super::{Adjacency, Edge, Weight};
+
+use std::collections::VecDeque;
+use std::fmt;
+
+/// a 2-coloring of vertices, every edge goes between left and right
+#[derive(Debug)]
+pub struct Bipartition {
+    is_left: Vec<bool>,
+}
+
+impl Bipartition {
+    /// the root of every bfs tree is put on the left
+    pub fn is_left(&self, vertex: usize) -> bool {
+        self.is_left[vertex]
+    }
+
+    /// left vertices in ascending order
+    pub fn left(&self) -> Vec<usize> {
+        (1..self.is_left.len()).filter(|&x| self.is_left[x]).collect()
+    }
+
+    /// right vertices in ascending order
+    pub fn right(&self) -> Vec<usize> {
+        (1..self.is_left.len()).filter(|&x| !self.is_left[x]).collect()
+    }
+}
+
+/// a cycle with odd length which makes 2-coloring impossible
+/// `edges[i]` connects `vertices[i]` and `vertices[(i+1) % vertices.len()]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct OddCycle {
+    vertices: Vec<usize>,
+    edges: Vec<usize>,
+}
+
+impl OddCycle {
+    pub fn vertices(&self) -> &[usize] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> &[usize] {
+        &self.edges
+    }
+}
+
+impl fmt::Display for OddCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "odd cycle found through vertices: {:?}", self.vertices)
+    }
+}
+
+/// maximum cardinality matching of a bipartite graph
+#[derive(Debug)]
+pub struct Matching {
+    /// `(left vertex, right vertex)` in ascending order of left vertex
+    pairs: Vec<(usize, usize)>,
+    mate: Vec<Option<usize>>,
+    vertex_cover: Vec<usize>,
+}
+
+impl Matching {
+    pub fn size(&self) -> usize {
+        self.pairs.len()
+    }
+
+    pub fn pairs(&self) -> &[(usize, usize)] {
+        &self.pairs
+    }
+
+    /// the vertex matched with `vertex`
+    pub fn mate(&self, vertex: usize) -> Option<usize> {
+        self.mate[vertex]
+    }
+
+    /// minimum vertex cover in ascending order, its size equals to the size of matching
+    pub fn vertex_cover(&self) -> &[usize] {
+        &self.vertex_cover
+    }
+}
+
+
+/// color vertices by parity of bfs level and stop at the first edge inside a level
+struct BipartiteVisitor {
+    depth: Vec<usize>,
+    /// tree edge through which each vertex is discovered and the vertex it comes from
+    parent: Vec<Option<(usize, usize)>>,
+    cycle: Option<OddCycle>,
+}
+
+impl BipartiteVisitor {
+    /// walk up from both ends of `edge` to their lowest common ancestor,
+    /// both ends are on the same bfs level
+    fn odd_cycle<W>(&self, edge_index: usize, edge: &Edge<W>) -> OddCycle {
+        let (mut u, mut v) = (edge.in_vertex, edge.out_vertex);
+        let (mut vertices, mut edges) = (Vec::new(), Vec::new());
+        let (mut other_vertices, mut other_edges) = (Vec::new(), Vec::new());
+        while u != v {
+            let (u_edge, u_parent) = self.parent[u].unwrap();
+            let (v_edge, v_parent) = self.parent[v].unwrap();
+            vertices.push(u);
+            edges.push(u_edge);
+            other_vertices.push(v);
+            other_edges.push(v_edge);
+            u = u_parent;
+            v = v_parent;
+        }
+        vertices.push(u);
+        vertices.extend(other_vertices.into_iter().rev());
+        edges.extend(other_edges.into_iter().rev());
+        edges.push(edge_index);
+
+        OddCycle { vertices, edges }
+    }
+}
+
+impl<W> Visitor<W> for BipartiteVisitor {
+    fn tree_edge(&mut self, edge_index: usize, edge: &Edge<W>) -> Control {
+        self.depth[edge.out_vertex] = self.depth[edge.in_vertex] + 1;
+        self.parent[edge.out_vertex] = Some((edge_index, edge.in_vertex));
+        Control::Continue
+    }
+
+    fn non_tree_edge(&mut self, edge_index: usize, edge: &Edge<W>) -> Control {
+        if self.depth[edge.in_vertex] % 2 != self.depth[edge.out_vertex] % 2 {
+            return Control::Continue;
+        }
+        self.cycle = Some(self.odd_cycle(edge_index, edge));
+        Control::Stop
+    }
+}
+
+
+/// bipartite graphs, they should be built with `AdjacencyList::add_undirected_edge`
+impl<W: Weight, A: Adjacency<W>> Graph<W, A> {
+    /// 2-coloring by bfs, or an odd cycle if the graph is not bipartite
+    pub fn is_bipartite(&self) -> Result<Bipartition, OddCycle> {
+        let num_vertices = self.adj_list().num_vertices();
+        let mut visitor = BipartiteVisitor {
+            depth: vec![0; num_vertices + 1],
+            parent: vec![None; num_vertices + 1],
+            cycle: None,
+        };
+
+        self.bfs(&mut visitor);
+        match visitor.cycle {
+            Some(cycle) => Err(cycle),
+            None => Ok(Bipartition {
+                is_left: visitor.depth.iter().map(|&x| x % 2 == 0).collect(),
+            }),
+        }
+    }
+
+    /// [Hopcroft–Karp](https://en.wikipedia.org/wiki/Hopcroft%E2%80%93Karp_algorithm)
+    /// maximum matching in O(E√V), minimum vertex cover is given by
+    /// [König's theorem](https://en.wikipedia.org/wiki/K%C5%91nig%27s_theorem_(graph_theory))
+    pub fn hopcroft_karp(&self) -> Result<Matching, OddCycle> {
+        let bipartition = self.is_bipartite()?;
+        let adj_list = self.adj_list();
+        let num_vertices = adj_list.num_vertices();
+        let left = bipartition.left();
+
+        let mut mate: Vec<Option<usize>> = vec![None; num_vertices + 1];
+        // level of left vertices in the alternating bfs, `usize::MAX` means unreachable
+        let mut level = vec![usize::MAX; num_vertices + 1];
+        let mut queue = VecDeque::with_capacity(num_vertices);
+
+        loop {
+            // bfs from all free left vertices, stop at the first level which reaches free right vertices,
+            // so only shortest augmenting paths are used in this phase
+            let mut free_level = None;
+            for &vertex in left.iter() {
+                if mate[vertex].is_none() {
+                    level[vertex] = 0;
+                    queue.push_back(vertex);
+                } else {
+                    level[vertex] = usize::MAX;
+                }
+            }
+            while let Some(vertex) = queue.pop_front() {
+                if matches!(free_level, Some(x) if level[vertex] > x) {
+                    continue;
+                }
+                for edge in adj_list.edge_iterator_of_vertex(vertex) {
+                    match mate[edge.out_vertex] {
+                        None => {
+                            free_level.get_or_insert(level[vertex]);
+                        },
+                        Some(next_vertex) if level[next_vertex] == usize::MAX && free_level.is_none() => {
+                            level[next_vertex] = level[vertex] + 1;
+                            queue.push_back(next_vertex);
+                        },
+                        _ => {},
+                    }
+                }
+            }
+            queue.clear();
+            let free_level = match free_level {
+                Some(x) => x,
+                None => break,
+            };
+
+            // dfs along levels, each left vertex keeps its remaining edges during one phase
+            let mut edge_iterators: Vec<A::IndexedEdgeIter<'_>> = (0..=num_vertices)
+                .map(|x| adj_list.indexed_edge_iterator_of_vertex(x))
+                .collect();
+            for &root in left.iter() {
+                if mate[root].is_some() {
+                    continue;
+                }
+
+                let mut stack = vec![root];
+                // right vertex after each left vertex on stack
+                let mut path = Vec::new();
+                while let Some(&vertex) = stack.last() {
+                    match edge_iterators[vertex].next() {
+                        Some((_, edge)) => match mate[edge.out_vertex] {
+                            None if level[vertex] == free_level => {
+                                path.push(edge.out_vertex);
+                                for (&left_vertex, &right_vertex) in stack.iter().zip(path.iter()) {
+                                    mate[left_vertex] = Some(right_vertex);
+                                    mate[right_vertex] = Some(left_vertex);
+                                }
+                                break;
+                            },
+                            Some(next_vertex) if level[vertex] < free_level && level[next_vertex] == level[vertex] + 1 => {
+                                path.push(edge.out_vertex);
+                                stack.push(next_vertex);
+                            },
+                            _ => {},
+                        },
+                        None => {
+                            // dead end, never visit it again in this phase
+                            level[vertex] = usize::MAX;
+                            stack.pop();
+                            path.pop();
+                        }
+                    }
+                }
+            }
+        }
+
+        // vertices reachable from free left vertices by alternating paths
+        let mut reachable = vec![false; num_vertices + 1];
+        for &vertex in left.iter() {
+            if mate[vertex].is_none() {
+                reachable[vertex] = true;
+                queue.push_back(vertex);
+            }
+        }
+        while let Some(vertex) = queue.pop_front() {
+            for edge in adj_list.edge_iterator_of_vertex(vertex) {
+                if reachable[edge.out_vertex] {
+                    continue;
+                }
+                reachable[edge.out_vertex] = true;
+                if let Some(next_vertex) = mate[edge.out_vertex] {
+                    reachable[next_vertex] = true;
+                    queue.push_back(next_vertex);
+                }
+            }
+        }
+
+        Ok(Matching {
+            pairs: left
+                .iter()
+                .filter_map(|&x| mate[x].map(|y| (x, y)))
+                .collect(),
+            vertex_cover: (1..=num_vertices)
+                .filter(|&x| bipartition.is_left(x) != reachable[x])
+                .collect(),
+            mate,
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::utils::undirected_graph;
+
+    fn assert_odd_cycle(graph: &Graph, cycle: &OddCycle) {
+        let (vertices, edges) = (cycle.vertices(), cycle.edges());
+        assert_eq!(vertices.len() % 2, 1);
+        assert_eq!(vertices.len(), edges.len());
+        for (i, &edge_index) in edges.iter().enumerate() {
+            let edge = graph.adj_list().edge(edge_index);
+            let mut expected = vec![vertices[i], vertices[(i + 1) % vertices.len()]];
+            let mut endpoints = vec![edge.in_vertex(), edge.out_vertex()];
+            expected.sort();
+            endpoints.sort();
+            assert_eq!(endpoints, expected);
+        }
+    }
+
+    #[test]
+    fn test_is_bipartite() {
+        // a square and a separate path
+        let graph = undirected_graph(7, &[(1, 2), (2, 3), (3, 4), (4, 1), (5, 6), (6, 7)]);
+        let bipartition = graph.is_bipartite().unwrap();
+        assert_eq!(bipartition.left(), vec![1, 3, 5, 7]);
+        assert_eq!(bipartition.right(), vec![2, 4, 6]);
+
+        // a pentagon with a pendant edge
+        let graph = undirected_graph(6, &[(1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (3, 6)]);
+        let cycle = graph.is_bipartite().unwrap_err();
+        assert_eq!(cycle.vertices().len(), 5);
+        assert_odd_cycle(&graph, &cycle);
+
+        let graph = undirected_graph(2, &[(1, 2), (2, 2)]);
+        let cycle = graph.is_bipartite().unwrap_err();
+        assert_eq!(cycle.vertices(), &[2]);
+        assert_odd_cycle(&graph, &cycle);
+    }
+
+    #[test]
+    fn test_hopcroft_karp() {
+        // left 1..=4 and right 5..=8, vertex 1 and 2 compete for vertex 5
+        let edges = [(1, 5), (2, 5), (3, 5), (3, 6), (3, 7), (4, 7), (4, 8)];
+        let graph = undirected_graph(9, &edges);
+        let matching = graph.hopcroft_karp().unwrap();
+
+        assert_eq!(matching.size(), 3);
+        for &(left_vertex, right_vertex) in matching.pairs() {
+            assert_eq!(matching.mate(left_vertex), Some(right_vertex));
+            assert_eq!(matching.mate(right_vertex), Some(left_vertex));
+            assert!(edges.contains(&(left_vertex, right_vertex)));
+        }
+        assert_eq!(matching.mate(9), None);
+
+        let cover = matching.vertex_cover();
+        assert_eq!(cover.len(), matching.size());
+        for &(u, v) in edges.iter() {
+            assert!(cover.contains(&u) || cover.contains(&v));
+        }
+    }
+
+    #[test]
+    fn test_hopcroft_karp_perfect_matching() {
+        // augmenting path has to go through the whole chain
+        let graph = undirected_graph(8, &[(1, 5), (1, 6), (2, 6), (2, 7), (3, 7), (3, 8), (4, 5)]);
+        let matching = graph.hopcroft_karp().unwrap();
+        assert_eq!(matching.size(), 4);
+        assert_eq!(matching.pairs(), &[(1, 6), (2, 7), (3, 8), (4, 5)]);
+        assert_eq!(matching.vertex_cover().len(), 4);
+
+        let graph = undirected_graph(3, &[(1, 2), (2, 3), (3, 1)]);
+        assert!(graph.hopcroft_karp().is_err());
+    }
+
+    #[test]
+    fn test_hopcroft_karp_random() {
+        use rand::Rng;
+
+        /// simple augmenting path search from every left vertex
+        fn kuhn(vertex: usize, adjacent: &[Vec<usize>], mate: &mut [Option<usize>], visited: &mut [bool]) -> bool {
+            for &next_vertex in adjacent[vertex].iter() {
+                if visited[next_vertex] {
+                    continue;
+                }
+                visited[next_vertex] = true;
+                if mate[next_vertex].is_none_or(|x| kuhn(x, adjacent, mate, visited)) {
+                    mate[next_vertex] = Some(vertex);
+                    return true;
+                }
+            }
+            false
+        }
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            // left 1..=8 and right 9..=16
+            let mut edges = Vec::new();
+            for left_vertex in 1..=8 {
+                for right_vertex in 9..=16 {
+                    if rng.gen_bool(0.2) {
+                        edges.push((left_vertex, right_vertex));
+                    }
+                }
+            }
+            let mut adjacent = vec![Vec::new(); 9];
+            for &(left_vertex, right_vertex) in edges.iter() {
+                adjacent[left_vertex].push(right_vertex);
+            }
+            let mut mate = vec![None; 17];
+            let expected = (1..=8).filter(|&x| kuhn(x, &adjacent, &mut mate, &mut [false; 17])).count();
+
+            let matching = undirected_graph(16, &edges).hopcroft_karp().unwrap();
+            assert_eq!(matching.size(), expected);
+            assert_eq!(matching.vertex_cover().len(), expected);
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use super::algorithms::Graph;
+use super::{AdjacencyList, Weight};
+
+use std::collections::HashMap;
+use std::fs;
+use std::hash::Hash;
+
+/// bidirectional mapping between labels and dense indices of vertices,
+/// indices are started from 1 in order of first appearance
+#[derive(Debug, Clone)]
+pub struct SymbolTable<L> {
+    index: HashMap<L, usize>,
+    /// `labels[i]` is the label of vertex `i + 1`
+    labels: Vec<L>,
+}
+
+impl<L: Hash + Eq + Clone> SymbolTable<L> {
+    pub fn new() -> SymbolTable<L> {
+        SymbolTable {
+            index: HashMap::new(),
+            labels: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// index of `label`, a new index is assigned if `label` has not been seen
+    pub fn insert(&mut self, label: L) -> usize {
+        if let Some(&index) = self.index.get(&label) {
+            return index;
+        }
+        self.labels.push(label.clone());
+        self.index.insert(label, self.labels.len());
+        self.labels.len()
+    }
+
+    pub fn index_of(&self, label: &L) -> Option<usize> {
+        self.index.get(label).copied()
+    }
+
+    pub fn label_of(&self, index: usize) -> &L {
+        match index {
+            x if x > 0 && x <= self.labels.len() => &self.labels[x - 1],
+            _ => panic!("vertex with index {} does not exist!", index),
+        }
+    }
+
+    /// labels in order of index
+    pub fn labels(&self) -> &[L] {
+        &self.labels
+    }
+}
+
+impl<L: Hash + Eq + Clone> Default for SymbolTable<L> {
+    fn default() -> Self {
+        SymbolTable::new()
+    }
+}
+
+
+/// graph whose vertices are labeled, algorithms run on `graph()` with indices
+/// and their results are translated back by `symbols()`
+pub struct LabeledGraph<L, W = i32> {
+    graph: Graph<W>,
+    symbols: SymbolTable<L>,
+}
+
+impl<L: Hash + Eq + Clone, W: Weight> LabeledGraph<L, W> {
+    pub fn graph(&self) -> &Graph<W> {
+        &self.graph
+    }
+
+    pub fn symbols(&self) -> &SymbolTable<L> {
+        &self.symbols
+    }
+
+    /// index of vertex labeled by `label`, panic if there is no such vertex
+    pub fn vertex(&self, label: &L) -> usize {
+        match self.symbols.index_of(label) {
+            Some(x) => x,
+            None => panic!("vertex with this label does not exist!"),
+        }
+    }
+
+    pub fn label(&self, vertex: usize) -> &L {
+        self.symbols.label_of(vertex)
+    }
+
+    /// translate vertices such as `Path::vertices` or a topological order into labels
+    pub fn labels(&self, vertices: &[usize]) -> Vec<&L> {
+        vertices.iter().map(|&x| self.symbols.label_of(x)).collect()
+    }
+
+    pub fn into_parts(self) -> (Graph<W>, SymbolTable<L>) {
+        (self.graph, self.symbols)
+    }
+}
+
+
+/// collect labeled vertices and edges, the number of vertices and edges is not needed up front
+pub struct GraphBuilder<L, W = i32> {
+    symbols: SymbolTable<L>,
+    /// (in vertex, out vertex, weight) in order of adding
+    edges: Vec<(usize, usize, W)>,
+}
+
+impl<L: Hash + Eq + Clone, W: Weight> GraphBuilder<L, W> {
+    pub fn new() -> Self {
+        GraphBuilder {
+            symbols: SymbolTable::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// add a vertex without edges, nothing happens if `label` already exists
+    pub fn add_vertex(&mut self, label: L) -> &mut Self {
+        self.symbols.insert(label);
+        self
+    }
+
+    pub fn add_directed_edge(&mut self, in_label: L, out_label: L, weight: W) -> &mut Self {
+        let in_vertex = self.symbols.insert(in_label);
+        let out_vertex = self.symbols.insert(out_label);
+        self.edges.push((in_vertex, out_vertex, weight));
+        self
+    }
+
+    /// same as `AdjacencyList::add_undirected_edge`, two directed edges with adjacent indices are added
+    pub fn add_undirected_edge(&mut self, in_label: L, out_label: L, weight: W) -> &mut Self {
+        self.add_directed_edge(in_label.clone(), out_label.clone(), weight);
+        self.add_directed_edge(out_label, in_label, weight)
+    }
+
+    /// index of edges is the order in which they are added, started from 1
+    pub fn build(self) -> LabeledGraph<L, W> {
+        let mut adj_list = AdjacencyList::new(self.symbols.len(), self.edges.len());
+        for (in_vertex, out_vertex, weight) in self.edges {
+            adj_list.add_directed_edge(in_vertex, out_vertex, weight);
+        }
+
+        LabeledGraph {
+            graph: Graph::new(adj_list),
+            symbols: self.symbols,
+        }
+    }
+}
+
+impl<L: Hash + Eq + Clone, W: Weight> Default for GraphBuilder<L, W> {
+    fn default() -> Self {
+        GraphBuilder::new()
+    }
+}
+
+
+/// every line is `in_label out_label weight` for a directed edge,
+/// or a single `label` for a vertex without edges, blank lines are skipped
+pub fn load_labeled_graph_from_file<W: Weight>(file_name: &str) -> LabeledGraph<String, W> {
+    let content = fs::read_to_string(file_name)
+        .unwrap_or_else(|_| panic!("fail to read file: `{}`", file_name));
+
+    let mut builder = GraphBuilder::new();
+    for line in content.lines() {
+        let line: Vec<&str> = line.split_ascii_whitespace().collect();
+        match line.as_slice() {
+            [] => {},
+            [label] => {
+                builder.add_vertex(label.to_string());
+            },
+            [in_label, out_label, weight] => {
+                let weight = match weight.parse::<W>() {
+                    Ok(x) => x,
+                    Err(_) => panic!("fail to parse weight: `{}`!", weight),
+                };
+                builder.add_directed_edge(in_label.to_string(), out_label.to_string(), weight);
+            },
+            _ => panic!("invalid line: `{}`!", line.join(" ")),
+        }
+    }
+
+    builder.build()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SERVICES_GRAPH_FILE: &str = "src/graph/examples/services.txt";
+
+    #[test]
+    fn test_symbol_table() {
+        let mut symbols = SymbolTable::new();
+        assert_eq!(symbols.insert("b"), 1);
+        assert_eq!(symbols.insert("a"), 2);
+        assert_eq!(symbols.insert("b"), 1);
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(symbols.index_of(&"a"), Some(2));
+        assert_eq!(symbols.index_of(&"c"), None);
+        assert_eq!(symbols.label_of(1), &"b");
+        assert_eq!(symbols.labels(), &["b", "a"]);
+    }
+
+    #[test]
+    fn test_graph_builder() {
+        let mut builder = GraphBuilder::new();
+        builder
+            .add_undirected_edge((0, 0), (0, 1), 1)
+            .add_undirected_edge((0, 1), (1, 1), 1)
+            .add_vertex((5, 5));
+        let labeled_graph = builder.build();
+
+        let graph = labeled_graph.graph();
+        assert_eq!(graph.adj_list().num_vertices(), 4);
+        assert_eq!(graph.adj_list().num_edges(), 4);
+        let edge = graph.adj_list().edge(3);
+        assert_eq!(labeled_graph.labels(&[edge.in_vertex(), edge.out_vertex()]), vec![&(0, 1), &(1, 1)]);
+        assert!(graph.adj_list().edge_iterator_of_vertex(labeled_graph.vertex(&(5, 5))).next().is_none());
+    }
+
+    #[test]
+    fn test_load_labeled_graph_from_file() {
+        let services: LabeledGraph<String> = load_labeled_graph_from_file(SERVICES_GRAPH_FILE);
+        assert_eq!(services.symbols().len(), 7);
+        assert_eq!(services.graph().adj_list().num_edges(), 7);
+
+        let gateway = services.vertex(&"gateway".to_string());
+        let billing = services.vertex(&"billing".to_string());
+        let path = services.graph().dijkstra(gateway).path_to(billing).unwrap();
+        assert_eq!(path.cost(), 11);
+        assert_eq!(services.labels(path.vertices()), vec!["gateway", "auth", "users", "billing"]);
+
+        let order = services.graph().topological_sort().unwrap();
+        let order = services.labels(&order);
+        let position = |label: &str| order.iter().position(|x| x.as_str() == label).unwrap();
+        assert!(position("gateway") < position("catalog"));
+        assert!(position("inventory") < position("billing"));
+
+        let audit = services.vertex(&"audit".to_string());
+        assert!(!services.graph().dijkstra(gateway).is_reachable(audit));
+    }
+}
@@ -0,0 +1,269 @@
+use super::algorithms::Graph;
+use super::{Adjacency, Weight};
+
+use crate::heap::{BinaryMinHeap, HeapElement, HeapMethod};
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+/// order in which vertices are greedily colored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColoringStrategy {
+    /// ascending order of vertex
+    Natural,
+    /// [Welsh-Powell](https://en.wikipedia.org/wiki/Greedy_coloring#Choice_of_ordering),
+    /// descending order of degree
+    LargestFirst,
+    /// repeatedly remove the vertex with smallest degree, then color in reverse order of removal,
+    /// uses at most degeneracy + 1 colors
+    SmallestLast,
+    /// [DSatur](https://en.wikipedia.org/wiki/DSatur), next vertex has the most distinct colors
+    /// among its neighbors, ties are broken by degree
+    DSatur,
+}
+
+/// color of each vertex, colors are started from 0
+#[derive(Debug)]
+pub struct Coloring {
+    /// index 0 is never used
+    colors: Vec<usize>,
+    num_colors: usize,
+}
+
+impl Coloring {
+    pub fn color(&self, vertex: usize) -> usize {
+        self.colors[vertex]
+    }
+
+    pub fn colors(&self) -> &[usize] {
+        &self.colors
+    }
+
+    pub fn num_colors(&self) -> usize {
+        self.num_colors
+    }
+
+    /// vertices of each color in ascending order
+    pub fn classes(&self) -> Vec<Vec<usize>> {
+        let mut classes = vec![Vec::new(); self.num_colors];
+        for vertex in 1..self.colors.len() {
+            classes[self.colors[vertex]].push(vertex);
+        }
+        classes
+    }
+}
+
+
+/// [vertex coloring](https://en.wikipedia.org/wiki/Graph_coloring) of undirected graphs
+/// built with `AdjacencyList::add_undirected_edge`, self loops are ignored
+impl<W: Weight, A: Adjacency<W>> Graph<W, A> {
+    /// greedy coloring, every vertex takes the smallest color unused by its colored neighbors
+    pub fn coloring(&self, strategy: ColoringStrategy) -> Coloring {
+        let num_vertices = self.adj_list().num_vertices();
+        let order: Vec<usize> = match strategy {
+            ColoringStrategy::Natural => (1..=num_vertices).collect(),
+            ColoringStrategy::LargestFirst => {
+                let degree = self.degrees();
+                let mut order: Vec<usize> = (1..=num_vertices).collect();
+                order.sort_by_key(|&x| Reverse(degree[x]));
+                order
+            },
+            ColoringStrategy::SmallestLast => self.smallest_last_order(),
+            ColoringStrategy::DSatur => return self.dsatur(),
+        };
+
+        let mut colors = vec![usize::MAX; num_vertices + 1];
+        // `forbidden[c] == vertex` means color c is used by a neighbor of vertex
+        let mut forbidden = vec![0; num_vertices + 1];
+        let mut num_colors = 0;
+        for vertex in order {
+            for edge in self.adj_list().edge_iterator_of_vertex(vertex) {
+                let color = colors[edge.out_vertex];
+                if color != usize::MAX {
+                    forbidden[color] = vertex;
+                }
+            }
+            let color = (0..).find(|&x| forbidden[x] != vertex).unwrap();
+            colors[vertex] = color;
+            num_colors = num_colors.max(color + 1);
+        }
+        colors[0] = 0;
+
+        Coloring { colors, num_colors }
+    }
+
+    /// `colors[vertex]` is the color of vertex, index 0 is ignored,
+    /// adjacent vertices must have different colors, self loops are ignored
+    pub fn is_proper_coloring(&self, colors: &[usize]) -> bool {
+        let num_vertices = self.adj_list().num_vertices();
+        if colors.len() != num_vertices + 1 {
+            return false;
+        }
+        (1..=num_vertices).all(|vertex| {
+            self.adj_list()
+                .edge_iterator_of_vertex(vertex)
+                .filter(|edge| edge.out_vertex != vertex)
+                .all(|edge| colors[vertex] != colors[edge.out_vertex])
+        })
+    }
+
+    /// number of edges to other vertices
+    fn degrees(&self) -> Vec<usize> {
+        (0..=self.adj_list().num_vertices())
+            .map(|vertex| {
+                self.adj_list()
+                    .edge_iterator_of_vertex(vertex)
+                    .filter(|edge| edge.out_vertex != vertex)
+                    .count()
+            })
+            .collect()
+    }
+
+    /// bucket queue by current degree in O(V + E)
+    fn smallest_last_order(&self) -> Vec<usize> {
+        let num_vertices = self.adj_list().num_vertices();
+        let mut degree = self.degrees();
+        let mut buckets = vec![Vec::new(); num_vertices + 1];
+        for (vertex, &x) in degree.iter().enumerate().skip(1) {
+            buckets[x].push(vertex);
+        }
+
+        let mut removed = vec![false; num_vertices + 1];
+        let mut order = Vec::with_capacity(num_vertices);
+        let mut min_degree = 0;
+        while order.len() < num_vertices {
+            // a bucket may hold stale entries of vertices whose degree has decreased
+            let vertex = match buckets[min_degree].pop() {
+                Some(x) if removed[x] || degree[x] != min_degree => continue,
+                Some(x) => x,
+                None => {
+                    min_degree += 1;
+                    continue;
+                }
+            };
+
+            removed[vertex] = true;
+            order.push(vertex);
+            for edge in self.adj_list().edge_iterator_of_vertex(vertex) {
+                let next_vertex = edge.out_vertex;
+                if !removed[next_vertex] {
+                    degree[next_vertex] -= 1;
+                    buckets[degree[next_vertex]].push(next_vertex);
+                    min_degree = min_degree.min(degree[next_vertex]);
+                }
+            }
+        }
+
+        order.reverse();
+        order
+    }
+
+    fn dsatur(&self) -> Coloring {
+        let num_vertices = self.adj_list().num_vertices();
+        let degree = self.degrees();
+        // distinct colors among neighbors of each vertex
+        let mut saturation: Vec<HashSet<usize>> = vec![HashSet::new(); num_vertices + 1];
+        let mut colors = vec![usize::MAX; num_vertices + 1];
+        let mut num_colors = 0;
+
+        let mut heap = BinaryMinHeap::with_capacity(num_vertices);
+        for (vertex, &x) in degree.iter().enumerate().skip(1) {
+            heap.push(HeapElement::new((Reverse(0), Reverse(x), vertex), vertex));
+        }
+
+        while let Some(element) = heap.pop() {
+            let vertex = element.track_id();
+            let color = (0..).find(|x| !saturation[vertex].contains(x)).unwrap();
+            colors[vertex] = color;
+            num_colors = num_colors.max(color + 1);
+
+            for edge in self.adj_list().edge_iterator_of_vertex(vertex) {
+                let next_vertex = edge.out_vertex;
+                if colors[next_vertex] == usize::MAX && saturation[next_vertex].insert(color) {
+                    let key = (Reverse(saturation[next_vertex].len()), Reverse(degree[next_vertex]), next_vertex);
+                    heap.push(HeapElement::new(key, next_vertex));
+                }
+            }
+        }
+        colors[0] = 0;
+
+        Coloring { colors, num_colors }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::utils::undirected_graph;
+
+    const STRATEGIES: [ColoringStrategy; 4] = [
+        ColoringStrategy::Natural,
+        ColoringStrategy::LargestFirst,
+        ColoringStrategy::SmallestLast,
+        ColoringStrategy::DSatur,
+    ];
+
+    #[test]
+    fn test_coloring() {
+        // wheel with 5 spokes, the odd rim needs 3 colors and the hub needs one more
+        let graph = undirected_graph(6, &[
+            (1, 2), (2, 3), (3, 4), (4, 5), (5, 1),
+            (6, 1), (6, 2), (6, 3), (6, 4), (6, 5),
+        ]);
+        for &strategy in STRATEGIES.iter() {
+            let coloring = graph.coloring(strategy);
+            assert!(graph.is_proper_coloring(coloring.colors()));
+            assert_eq!(coloring.num_colors(), 4);
+            assert_eq!(coloring.classes().iter().map(|x| x.len()).sum::<usize>(), 6);
+        }
+    }
+
+    #[test]
+    fn test_coloring_crown() {
+        // crown graph, natural order alternates sides and needs one color per pair
+        let mut edges = Vec::new();
+        for i in 1..=4 {
+            for j in 1..=4 {
+                if i != j {
+                    edges.push((2 * i - 1, 2 * j));
+                }
+            }
+        }
+        let graph = undirected_graph(8, &edges);
+        assert_eq!(graph.coloring(ColoringStrategy::Natural).num_colors(), 4);
+        assert_eq!(graph.coloring(ColoringStrategy::DSatur).num_colors(), 2);
+        for &strategy in STRATEGIES.iter() {
+            assert!(graph.is_proper_coloring(graph.coloring(strategy).colors()));
+        }
+    }
+
+    #[test]
+    fn test_is_proper_coloring() {
+        let graph = undirected_graph(3, &[(1, 2), (2, 3)]);
+        assert!(graph.is_proper_coloring(&[0, 0, 1, 0]));
+        assert!(!graph.is_proper_coloring(&[0, 0, 0, 1]));
+        assert!(!graph.is_proper_coloring(&[0, 0, 1]));
+    }
+
+    #[test]
+    fn test_coloring_self_loop() {
+        let graph = undirected_graph(3, &[(1, 2), (2, 2), (2, 3)]);
+        assert!(graph.is_proper_coloring(&[0, 0, 1, 0]));
+        for &strategy in STRATEGIES.iter() {
+            let coloring = graph.coloring(strategy);
+            assert!(graph.is_proper_coloring(coloring.colors()));
+            assert_eq!(coloring.num_colors(), 2);
+        }
+    }
+
+    #[test]
+    fn test_smallest_last_order() {
+        // a triangle with a long tail, the tail is removed first and colored last
+        let graph = undirected_graph(6, &[(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 6)]);
+        let order = graph.smallest_last_order();
+        assert_eq!(order.len(), 6);
+        assert_eq!(&order[3..], &[4, 5, 6]);
+        assert_eq!(graph.coloring(ColoringStrategy::SmallestLast).num_colors(), 3);
+    }
+}
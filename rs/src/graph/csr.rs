@@ -0,0 +1,256 @@
+use super::{Adjacency, AdjacencyList, Edge, Weight};
+
+use std::fs;
+use std::iter::{Cloned, Copied, Zip};
+use std::slice;
+
+/// edges grouped by one of their vertices, edges of vertex `v` are `edges[offsets[v]..offsets[v + 1]]`
+struct Rows<W> {
+    offsets: Vec<usize>,
+    edges: Vec<Edge<W>>,
+    /// index of each edge in `edges`
+    edge_indices: Vec<usize>,
+}
+
+impl<W: Weight> Rows<W> {
+    /// stable counting sort of `edges` by `key`
+    fn new(num_vertices: usize, edges: &[(usize, &Edge<W>)], key: fn(&Edge<W>) -> usize) -> Rows<W> {
+        let mut offsets = vec![0; num_vertices + 2];
+        for (_, edge) in edges {
+            offsets[key(edge) + 1] += 1;
+        }
+        for vertex in 1..offsets.len() {
+            offsets[vertex] += offsets[vertex - 1];
+        }
+
+        let mut slots: Vec<Option<(usize, Edge<W>)>> = Vec::with_capacity(edges.len());
+        slots.resize_with(edges.len(), || None);
+        let mut cursor = offsets.clone();
+        for &(edge_index, edge) in edges {
+            let vertex = key(edge);
+            slots[cursor[vertex]] = Some((edge_index, Edge::new(edge.in_vertex, edge.out_vertex, edge.weight)));
+            cursor[vertex] += 1;
+        }
+        let (edge_indices, edges) = slots.into_iter().map(Option::unwrap).unzip();
+
+        Rows { offsets, edges, edge_indices }
+    }
+
+    fn edges(&self, vertex: usize) -> &[Edge<W>] {
+        &self.edges[self.offsets[vertex]..self.offsets[vertex + 1]]
+    }
+
+    fn edge_indices(&self, vertex: usize) -> &[usize] {
+        &self.edge_indices[self.offsets[vertex]..self.offsets[vertex + 1]]
+    }
+
+    fn indexed_edges(&self, vertex: usize) -> CsrEdgeIterator<'_, W> {
+        self.edge_indices(vertex).iter().copied().zip(self.edges(vertex).iter().cloned())
+    }
+}
+
+
+pub type CsrEdgeIterator<'a, W> = Zip<Copied<slice::Iter<'a, usize>>, Cloned<slice::Iter<'a, Edge<W>>>>;
+
+/// [compressed sparse row](https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format)),
+/// a frozen graph whose edges of each vertex are stored contiguously,
+/// index of edges and order of edges of each vertex are the same as `AdjacencyList`
+pub struct CompressedSparseRow<W = i32> {
+    num_vertices: usize,
+    num_edges: usize,
+    /// edges grouped by in vertex
+    outgoing: Rows<W>,
+    /// edges grouped by out vertex
+    incoming: Rows<W>,
+    /// position in `outgoing.edges` of each edge, `usize::MAX` for index without edge
+    positions: Vec<usize>,
+}
+
+impl<W: Weight> CompressedSparseRow<W> {
+    /// `edges` are pairs of index and edge in ascending order of index,
+    /// `num_edges` is the upper bound of index
+    fn new(num_vertices: usize, num_edges: usize, edges: &[(usize, &Edge<W>)]) -> CompressedSparseRow<W> {
+        // `AdjacencyList` links the latest edge first
+        let edges: Vec<(usize, &Edge<W>)> = edges.iter().rev().copied().collect();
+        let outgoing = Rows::new(num_vertices, &edges, |edge| edge.in_vertex);
+        let incoming = Rows::new(num_vertices, &edges, |edge| edge.out_vertex);
+        let mut positions = vec![usize::MAX; num_edges + 1];
+        for (position, &edge_index) in outgoing.edge_indices.iter().enumerate() {
+            positions[edge_index] = position;
+        }
+
+        CompressedSparseRow {
+            num_vertices,
+            num_edges,
+            outgoing,
+            incoming,
+            positions,
+        }
+    }
+
+    pub fn from_adj_list(adj_list: &AdjacencyList<W>) -> CompressedSparseRow<W> {
+        let edges: Vec<(usize, &Edge<W>)> = adj_list.edges
+            .iter()
+            .enumerate()
+            .filter_map(|(edge_index, edge)| edge.as_ref().map(|x| (edge_index, x)))
+            .collect();
+        CompressedSparseRow::new(adj_list.num_vertices(), adj_list.num_edges(), &edges)
+    }
+
+    pub fn num_vertices(&self) -> usize {
+        self.num_vertices
+    }
+
+    /// upper bound of index of edge
+    pub fn num_edges(&self) -> usize {
+        self.num_edges
+    }
+
+    /// edges whose in vertex is `vertex`
+    pub fn edges_of_vertex(&self, vertex: usize) -> &[Edge<W>] {
+        self.outgoing.edges(vertex)
+    }
+
+    /// `edge_indices_of_vertex(vertex)[i]` is the index of `edges_of_vertex(vertex)[i]`
+    pub fn edge_indices_of_vertex(&self, vertex: usize) -> &[usize] {
+        self.outgoing.edge_indices(vertex)
+    }
+
+    /// edges whose out vertex is `vertex`
+    pub fn incoming_edges_of_vertex(&self, vertex: usize) -> &[Edge<W>] {
+        self.incoming.edges(vertex)
+    }
+
+    pub fn incoming_edge_indices_of_vertex(&self, vertex: usize) -> &[usize] {
+        self.incoming.edge_indices(vertex)
+    }
+
+    /// get edge by its index, index of edge is started from 1
+    pub fn edge(&self, edge_index: usize) -> &Edge<W> {
+        match self.positions.get(edge_index) {
+            Some(&x) if edge_index > 0 && x != usize::MAX => &self.outgoing.edges[x],
+            _ => panic!("edge with index {} does not exist!", edge_index),
+        }
+    }
+}
+
+impl<W: Weight> Adjacency<W> for CompressedSparseRow<W> {
+    type EdgeIter<'a> = Cloned<slice::Iter<'a, Edge<W>>> where W: 'a;
+    type IndexedEdgeIter<'a> = CsrEdgeIterator<'a, W> where W: 'a;
+
+    fn num_vertices(&self) -> usize {
+        self.num_vertices
+    }
+
+    fn num_edges(&self) -> usize {
+        self.num_edges
+    }
+
+    fn edge_iterator_of_vertex(&self, vertex: usize) -> Cloned<slice::Iter<'_, Edge<W>>> {
+        self.outgoing.edges(vertex).iter().cloned()
+    }
+
+    fn indexed_edge_iterator_of_vertex(&self, vertex: usize) -> CsrEdgeIterator<'_, W> {
+        self.outgoing.indexed_edges(vertex)
+    }
+
+    fn incoming_edge_iterator_of_vertex(&self, vertex: usize) -> CsrEdgeIterator<'_, W> {
+        self.incoming.indexed_edges(vertex)
+    }
+
+    fn edge(&self, edge_index: usize) -> Edge<W> {
+        self.edge(edge_index).clone()
+    }
+}
+
+
+/// same format as `load_adj_list_from_file`, edges are not linked one by one
+pub fn load_csr_from_file<W: Weight>(file_name: &str) -> CompressedSparseRow<W> {
+    let content = fs::read_to_string(file_name)
+        .unwrap_or_else(|_| panic!("fail to read file: `{}`", file_name));
+
+    let mut lines = content.lines();
+
+    let first_line: Vec<&str> = lines.next().unwrap().split_ascii_whitespace().collect();
+    let num_vertices = first_line[0].parse::<usize>().unwrap();
+    let num_edges = first_line[1].parse::<usize>().unwrap();
+
+    let mut edges = Vec::with_capacity(num_edges);
+    for line in lines {
+        let line: Vec<&str> = line.split_ascii_whitespace().collect();
+        let in_vertex = line[0].parse::<usize>().unwrap();
+        let out_vertex = line[1].parse::<usize>().unwrap();
+        let weight = match line[2].parse::<W>() {
+            Ok(x) => x,
+            Err(_) => panic!("fail to parse weight: `{}`!", line[2]),
+        };
+        if in_vertex == 0 || out_vertex == 0 || in_vertex > num_vertices || out_vertex > num_vertices {
+            panic!("vertex of edge `{} {}` does not exist!", in_vertex, out_vertex);
+        }
+        edges.push(Edge::new(in_vertex, out_vertex, weight));
+    }
+
+    let indexed_edges: Vec<(usize, &Edge<W>)> = edges
+        .iter()
+        .enumerate()
+        .map(|(i, edge)| (i + 1, edge))
+        .collect();
+    CompressedSparseRow::new(num_vertices, num_edges.max(edges.len()), &indexed_edges)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::algorithms::Graph;
+    use crate::graph::load_adj_list_from_file;
+
+    const TEST_GRAPH_FILE: &str = "src/graph/examples/test.txt";
+    const POSITIVE_SIMPLE_GRAPH_FILE: &str = "src/graph/examples/positive_simple.txt";
+
+    #[test]
+    fn test_csr_from_adj_list() {
+        let adj_list: AdjacencyList = load_adj_list_from_file(TEST_GRAPH_FILE);
+        let csr = CompressedSparseRow::from_adj_list(&adj_list);
+        assert_eq!(csr.num_vertices(), 4);
+        assert_eq!(csr.edge_indices_of_vertex(1), &[3, 1]);
+        assert_eq!(csr.edges_of_vertex(1)[0].out_vertex(), 4);
+        assert!(csr.edges_of_vertex(4).is_empty());
+        assert_eq!(csr.incoming_edge_indices_of_vertex(4), &[5, 4, 3]);
+        assert_eq!(csr.edge(2).weight(), 5);
+
+        for vertex in 1..=4 {
+            let from_list: Vec<usize> = adj_list.indexed_edge_iterator_of_vertex(vertex).map(|(x, _)| x).collect();
+            assert_eq!(csr.edge_indices_of_vertex(vertex), from_list.as_slice());
+        }
+    }
+
+    #[test]
+    fn test_load_csr_from_file() {
+        let csr: CompressedSparseRow = load_csr_from_file(TEST_GRAPH_FILE);
+        assert_eq!(csr.incoming_edge_indices_of_vertex(4), &[5, 4, 3]);
+        assert_eq!(csr.num_edges(), 5);
+        assert_eq!(csr.edge_indices_of_vertex(2), &[4, 2]);
+        assert_eq!(csr.edge(5).in_vertex(), 3);
+    }
+
+    #[test]
+    fn test_graph_algorithms_on_csr() {
+        let from_list: Graph = Graph::create_from_file(POSITIVE_SIMPLE_GRAPH_FILE);
+        let from_csr = Graph::new(load_csr_from_file::<i32>(POSITIVE_SIMPLE_GRAPH_FILE));
+        // neighbors are visited in the same order, so the results are exactly the same
+        for source in (1..=from_list.adj_list().num_vertices()).step_by(97) {
+            let expected = from_list.dijkstra(source);
+            let shortest_paths = from_csr.dijkstra(source);
+            assert_eq!(shortest_paths.distances(), expected.distances());
+            assert_eq!(from_csr.bfs_iter(source).count(), from_list.bfs_iter(source).count());
+            let target = from_list.adj_list().num_vertices() + 1 - source;
+            assert_eq!(
+                from_csr.bidirectional_dijkstra(source, target).map(|x| x.cost()),
+                expected.distance(target),
+            );
+        }
+        assert_eq!(from_csr.topological_sort(), from_list.topological_sort());
+        assert_eq!(from_csr.kosaraju_scc().components(), from_list.kosaraju_scc().components());
+    }
+}
@@ -0,0 +1,281 @@
+use super::algorithms::Graph;
+use super::{Adjacency, Weight};
+
+use crate::union_find::{DisjointSet, UnionFindMethod};
+
+/// how edges of `AdjacencyList` are interpreted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Directed,
+    /// every edge has a twin of the same weight in the opposite direction, e.g. built with
+    /// `AdjacencyList::add_undirected_edge` or `AdjacencyMatrix::set_undirected_edge`
+    Undirected,
+}
+
+/// whether a trail using every edge exactly once exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Eulerian {
+    /// closed trail, also returned by graphs without edges
+    Circuit,
+    /// open trail from `start` to `end`
+    Path { start: usize, end: usize },
+    NoTrail,
+}
+
+
+/// [Eulerian trail](https://en.wikipedia.org/wiki/Eulerian_path)
+impl<W: Weight, A: Adjacency<W>> Graph<W, A> {
+    /// degree conditions plus all edges lie in one (weakly) connected component
+    pub fn eulerian(&self, orientation: Orientation) -> Eulerian {
+        let adj_list = self.adj_list();
+        let num_vertices = adj_list.num_vertices();
+
+        // out degree minus in degree, or degree of undirected edges
+        let mut degree = vec![0i32; num_vertices + 1];
+        let mut disjoint_set = DisjointSet::one_based(num_vertices);
+        let mut some_vertex = None;
+        for vertex in 1..=num_vertices {
+            for edge in adj_list.edge_iterator_of_vertex(vertex) {
+                match orientation {
+                    Orientation::Directed => {
+                        degree[edge.in_vertex] += 1;
+                        degree[edge.out_vertex] -= 1;
+                    },
+                    Orientation::Undirected => degree[edge.in_vertex] += 1,
+                }
+                disjoint_set.union(edge.in_vertex, edge.out_vertex);
+                some_vertex = Some(vertex);
+            }
+        }
+
+        let some_vertex = match some_vertex {
+            Some(x) => x,
+            None => return Eulerian::Circuit,
+        };
+        for vertex in 1..=num_vertices {
+            if adj_list.edge_iterator_of_vertex(vertex).next().is_some()
+                && !disjoint_set.is_same_set(vertex, some_vertex)
+            {
+                return Eulerian::NoTrail;
+            }
+        }
+
+        let (mut starts, mut ends) = (Vec::new(), Vec::new());
+        for (vertex, &x) in degree.iter().enumerate().skip(1) {
+            match (orientation, x) {
+                (Orientation::Directed, 0) => {},
+                (Orientation::Directed, 1) => starts.push(vertex),
+                (Orientation::Directed, -1) => ends.push(vertex),
+                (Orientation::Directed, _) => return Eulerian::NoTrail,
+                (Orientation::Undirected, degree) if degree % 2 == 0 => {},
+                (Orientation::Undirected, _) if starts.is_empty() => starts.push(vertex),
+                (Orientation::Undirected, _) => ends.push(vertex),
+            }
+        }
+
+        match (starts.as_slice(), ends.as_slice()) {
+            ([], []) => Eulerian::Circuit,
+            (&[start], &[end]) => Eulerian::Path { start, end },
+            _ => Eulerian::NoTrail,
+        }
+    }
+
+    pub fn has_eulerian_circuit(&self, orientation: Orientation) -> bool {
+        self.eulerian(orientation) == Eulerian::Circuit
+    }
+
+    /// a circuit is also a path
+    pub fn has_eulerian_path(&self, orientation: Orientation) -> bool {
+        self.eulerian(orientation) != Eulerian::NoTrail
+    }
+
+    /// [Hierholzer](https://en.wikipedia.org/wiki/Eulerian_path#Hierholzer's_algorithm) in O(E),
+    /// edges are returned in walking order, an undirected edge is given by the direction it is walked
+    pub fn eulerian_trail(&self, orientation: Orientation) -> Option<Vec<usize>> {
+        let adj_list = self.adj_list();
+        let start = match self.eulerian(orientation) {
+            Eulerian::NoTrail => return None,
+            Eulerian::Path { start, .. } => start,
+            Eulerian::Circuit => match (1..=adj_list.num_vertices()).find(|&x| adj_list.edge_iterator_of_vertex(x).next().is_some()) {
+                Some(x) => x,
+                None => return Some(Vec::new()),
+            },
+        };
+
+        // remaining edges of each vertex which may be unused, edges before them have been walked
+        let mut current: Vec<A::IndexedEdgeIter<'_>> = (0..=adj_list.num_vertices())
+            .map(|x| adj_list.indexed_edge_iterator_of_vertex(x))
+            .collect();
+        let mut used = vec![false; adj_list.num_edges() + 1];
+        let twins = match orientation {
+            Orientation::Directed => Vec::new(),
+            Orientation::Undirected => self.twins(),
+        };
+        let mut trail = Vec::new();
+        // vertices of the walk in progress and edges through which they are reached
+        let mut stack: Vec<(usize, Option<usize>)> = vec![(start, None)];
+
+        while let Some(&(vertex, reached_by)) = stack.last() {
+            match current[vertex].find(|&(x, _)| !used[x]) {
+                Some((edge_index, edge)) => {
+                    used[edge_index] = true;
+                    if let Some(&Some(twin)) = twins.get(edge_index) {
+                        used[twin] = true;
+                    }
+                    stack.push((edge.out_vertex, Some(edge_index)));
+                },
+                None => {
+                    // no unused edges, vertex is settled in the trail
+                    stack.pop();
+                    if let Some(edge_index) = reached_by {
+                        trail.push(edge_index);
+                    }
+                },
+            }
+        }
+
+        trail.reverse();
+        Some(trail)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::matrix::AdjacencyMatrix;
+    use crate::graph::{AdjacencyList, Edge};
+
+    /// every edge is used once and consecutive edges share a vertex
+    fn assert_trail<A: Adjacency<i32>>(graph: &Graph<i32, A>, orientation: Orientation, trail: &[usize]) {
+        let adj_list = graph.adj_list();
+        let endpoints = |edge: Edge| match orientation {
+            Orientation::Directed => (edge.in_vertex(), edge.out_vertex()),
+            Orientation::Undirected => (edge.in_vertex().min(edge.out_vertex()), edge.in_vertex().max(edge.out_vertex())),
+        };
+        let mut expected: Vec<(usize, usize)> = (1..=adj_list.num_vertices())
+            .flat_map(|x| adj_list.edge_iterator_of_vertex(x))
+            .map(endpoints)
+            .collect();
+        expected.sort();
+        if orientation == Orientation::Undirected {
+            // both directions of an undirected edge have the same endpoints
+            expected = expected.into_iter().step_by(2).collect();
+        }
+
+        let mut edges: Vec<(usize, usize)> = trail.iter().map(|&x| endpoints(adj_list.edge(x))).collect();
+        edges.sort();
+        assert_eq!(edges, expected);
+
+        for pair in trail.windows(2) {
+            assert_eq!(adj_list.edge(pair[0]).out_vertex(), adj_list.edge(pair[1]).in_vertex());
+        }
+    }
+
+    #[test]
+    fn test_directed_eulerian() {
+        // two cycles sharing vertex 1 and 3
+        let mut adj_list = AdjacencyList::new(5, 7);
+        adj_list.add_directed_edge(1, 2, 1);
+        adj_list.add_directed_edge(2, 3, 1);
+        adj_list.add_directed_edge(3, 1, 1);
+        adj_list.add_directed_edge(1, 4, 1);
+        adj_list.add_directed_edge(4, 3, 1);
+        adj_list.add_directed_edge(3, 1, 1);
+        let graph = Graph::new(adj_list);
+        assert_eq!(graph.eulerian(Orientation::Directed), Eulerian::Circuit);
+        let trail = graph.eulerian_trail(Orientation::Directed).unwrap();
+        assert_trail(&graph, Orientation::Directed, &trail);
+        assert_eq!(graph.adj_list().edge(trail[0]).in_vertex(), 1);
+
+        let mut adj_list = AdjacencyList::new(5, 7);
+        adj_list.add_directed_edge(1, 2, 1);
+        adj_list.add_directed_edge(2, 3, 1);
+        adj_list.add_directed_edge(3, 1, 1);
+        adj_list.add_directed_edge(1, 4, 1);
+        adj_list.add_directed_edge(4, 3, 1);
+        adj_list.add_directed_edge(3, 5, 1);
+        let graph = Graph::new(adj_list);
+        // the last edge now leaves the circuit
+        assert_eq!(graph.eulerian(Orientation::Directed), Eulerian::Path { start: 1, end: 5 });
+        assert!(!graph.has_eulerian_circuit(Orientation::Directed));
+        let trail = graph.eulerian_trail(Orientation::Directed).unwrap();
+        assert_trail(&graph, Orientation::Directed, &trail);
+        assert_eq!(graph.adj_list().edge(*trail.last().unwrap()).out_vertex(), 5);
+    }
+
+    #[test]
+    fn test_undirected_eulerian() {
+        // house shape, the roof makes vertex 1 and 2 odd
+        let mut adj_list = AdjacencyList::new(5, 16);
+        adj_list.add_undirected_edge(1, 2, 1);
+        adj_list.add_undirected_edge(2, 3, 1);
+        adj_list.add_undirected_edge(3, 4, 1);
+        adj_list.add_undirected_edge(4, 1, 1);
+        adj_list.add_undirected_edge(1, 3, 1);
+        adj_list.add_undirected_edge(2, 4, 1);
+        adj_list.add_undirected_edge(3, 5, 1);
+        adj_list.add_undirected_edge(5, 4, 1);
+        let graph = Graph::new(adj_list);
+        assert_eq!(graph.eulerian(Orientation::Undirected), Eulerian::Path { start: 1, end: 2 });
+        let trail = graph.eulerian_trail(Orientation::Undirected).unwrap();
+        assert_trail(&graph, Orientation::Undirected, &trail);
+        assert_eq!(graph.adj_list().edge(trail[0]).in_vertex(), 1);
+
+        // the same edges seen as directed edges have a circuit
+        assert!(graph.has_eulerian_circuit(Orientation::Directed));
+    }
+
+    #[test]
+    fn test_undirected_eulerian_without_adjacent_twins() {
+        // a bowtie, two triangles sharing vertex 1, plus a self loop on vertex 2
+        let edges = [(1, 2), (2, 3), (3, 1), (1, 4), (4, 5), (5, 1), (2, 2)];
+
+        // twins are far apart and one edge has been removed and added again
+        let mut adj_list = AdjacencyList::new(5, 14);
+        for &(u, v) in edges.iter() {
+            adj_list.add_directed_edge(u, v, 1);
+        }
+        for &(u, v) in edges.iter().rev() {
+            adj_list.add_directed_edge(v, u, 1);
+        }
+        let removed = adj_list.remove_edge(1);
+        adj_list.add_directed_edge(removed.in_vertex(), removed.out_vertex(), 1);
+        let graph = Graph::new(adj_list);
+        assert_eq!(graph.eulerian(Orientation::Undirected), Eulerian::Circuit);
+        let trail = graph.eulerian_trail(Orientation::Undirected).unwrap();
+        assert_trail(&graph, Orientation::Undirected, &trail);
+
+        // a matrix has a single slot for the self loop, so it is left out
+        let mut matrix = AdjacencyMatrix::new(5);
+        for &(u, v) in edges[..6].iter() {
+            matrix.set_undirected_edge(u, v, 1);
+        }
+        let graph = Graph::new(matrix);
+        let trail = graph.eulerian_trail(Orientation::Undirected).unwrap();
+        assert_trail(&graph, Orientation::Undirected, &trail);
+    }
+
+    #[test]
+    fn test_not_eulerian() {
+        // two separate triangles
+        let mut adj_list = AdjacencyList::new(7, 12);
+        for &(u, v) in [(1, 2), (2, 3), (3, 1), (4, 5), (5, 6), (6, 4)].iter() {
+            adj_list.add_undirected_edge(u, v, 1);
+        }
+        let graph = Graph::new(adj_list);
+        assert_eq!(graph.eulerian(Orientation::Undirected), Eulerian::NoTrail);
+        assert!(graph.eulerian_trail(Orientation::Undirected).is_none());
+
+        // a star with three leaves
+        let mut adj_list = AdjacencyList::new(4, 6);
+        for &(u, v) in [(1, 2), (1, 3), (1, 4)].iter() {
+            adj_list.add_undirected_edge(u, v, 1);
+        }
+        let graph = Graph::new(adj_list);
+        assert!(!graph.has_eulerian_path(Orientation::Undirected));
+
+        let graph: Graph = Graph::new(AdjacencyList::new(3, 0));
+        assert_eq!(graph.eulerian_trail(Orientation::Directed), Some(Vec::new()));
+    }
+}
@@ -0,0 +1,231 @@
+use super::FlowNetwork;
+use crate::graph::{ShortestPaths, Weight};
+
+use crate::heap::{BinaryMinHeap, HeapElement, HeapMethod};
+
+/// result of min cost flow
+#[derive(Debug)]
+pub struct MinCostFlow {
+    value: i32,
+    /// accumulated in `i64` since flow times cost easily exceeds `i32`
+    cost: i64,
+    /// flow on each edge, index 0 is never used
+    flow: Vec<i32>,
+}
+
+impl MinCostFlow {
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    pub fn cost(&self) -> i64 {
+        self.cost
+    }
+
+    pub fn flow(&self, edge_index: usize) -> i32 {
+        self.flow[edge_index]
+    }
+}
+
+impl FlowNetwork {
+    /// send as much flow as possible from `source` to `sink` with minimum cost
+    pub fn min_cost_max_flow(&self, source: usize, sink: usize) -> MinCostFlow {
+        self.min_cost_flow(source, sink, None)
+    }
+
+    /// successive shortest paths with potentials, send at most `limit` units of flow,
+    /// the network must not contain a cycle with negative cost
+    pub fn min_cost_flow(&self, source: usize, sink: usize, limit: Option<i32>) -> MinCostFlow {
+        let limit = limit.unwrap_or(i32::MAX);
+        let mut residual = self.capacity.clone();
+        let mut potential = self.initial_potential(source);
+        let mut value = 0;
+        let mut cost: i64 = 0;
+
+        while value < limit && source != sink {
+            let shortest_paths = self.reduced_cost_dijkstra(source, &residual, &potential);
+            if !shortest_paths.is_reachable(sink) {
+                break;
+            }
+
+            // reduced costs stay non-negative on residual arcs
+            for (vertex, distance) in shortest_paths.distances().iter().enumerate().skip(1) {
+                if let Some(distance) = distance {
+                    potential[vertex] = potential[vertex].add_or_panic(*distance);
+                }
+            }
+
+            let mut path = Vec::new();
+            let mut current_vertex = sink;
+            while let Some(arc) = shortest_paths.predecessor(current_vertex) {
+                path.push(arc);
+                current_vertex = shortest_paths.predecessor_vertex(current_vertex).unwrap();
+            }
+
+            let delta = path.iter().map(|&x| residual[x]).min().unwrap().min(limit - value);
+            for &arc in path.iter() {
+                residual[arc] -= delta;
+                residual[arc ^ 1] += delta;
+                cost = cost.add_or_panic(delta as i64 * self.cost[arc] as i64);
+            }
+            value += delta;
+        }
+
+        MinCostFlow {
+            value,
+            cost,
+            flow: self.edge_flows(&residual),
+        }
+    }
+
+    /// bellman ford from source when some costs are negative, so reduced costs become non-negative
+    fn initial_potential(&self, source: usize) -> Vec<i64> {
+        let mut potential = vec![0; self.num_vertices + 1];
+        if self.cost.iter().step_by(2).all(|&x| x >= 0) {
+            return potential;
+        }
+
+        let mut reached = vec![false; self.num_vertices + 1];
+        reached[source] = true;
+        for round in 0..=self.num_vertices {
+            let mut relaxed = false;
+            for arc in (0..self.head.len()).step_by(2) {
+                let (in_vertex, out_vertex) = (self.tail(arc), self.head[arc]);
+                if self.capacity[arc] == 0 || !reached[in_vertex] {
+                    continue;
+                }
+                let new_potential = potential[in_vertex].add_or_panic(self.cost[arc] as i64);
+                if !reached[out_vertex] || new_potential < potential[out_vertex] {
+                    reached[out_vertex] = true;
+                    potential[out_vertex] = new_potential;
+                    relaxed = true;
+                }
+            }
+            if !relaxed {
+                break;
+            }
+            if round == self.num_vertices {
+                panic!("network contains a cycle with negative cost!");
+            }
+        }
+
+        potential
+    }
+
+    /// indexed dijkstra on residual arcs with cost reduced by `potential`,
+    /// predecessors of the result are arcs but not edges
+    fn reduced_cost_dijkstra(&self, source: usize, residual: &[i32], potential: &[i64]) -> ShortestPaths<i64> {
+        let mut heap: BinaryMinHeap<i64, usize> = BinaryMinHeap::with_capacity(self.num_vertices);
+        let mut settled = vec![false; self.num_vertices + 1];
+        let mut shortest_paths = ShortestPaths::new(source, self.num_vertices);
+
+        heap.push(HeapElement::new(0, source));
+
+        while let Some(element) = heap.pop() {
+            let vertex = element.track_id();
+            let distance = *element.key();
+            settled[vertex] = true;
+
+            let mut arc = self.first[vertex];
+            while arc != -1 {
+                let arc_index = arc as usize;
+                arc = self.next[arc_index];
+
+                let next_vertex = self.head[arc_index];
+                if residual[arc_index] == 0 || settled[next_vertex] {
+                    continue;
+                }
+
+                let reduced_cost = (self.cost[arc_index] as i64)
+                    .add_or_panic(potential[vertex].sub_or_panic(potential[next_vertex]));
+                let new_distance = distance.add_or_panic(reduced_cost);
+                match shortest_paths.distance(next_vertex) {
+                    Some(x) if x <= new_distance => {},
+                    _ => {
+                        shortest_paths.update(next_vertex, new_distance, vertex, arc_index);
+                        heap.push(HeapElement::new(new_distance, next_vertex));
+                    }
+                }
+            }
+        }
+
+        shortest_paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// workers 1..=3 and jobs 4..=6, source 7 and sink 8
+    fn assignment_network(costs: &[[i32; 3]; 3]) -> FlowNetwork {
+        let mut network = FlowNetwork::new(8);
+        for worker in 1..=3 {
+            network.add_edge(7, worker, 1);
+            network.add_edge(worker + 3, 8, 1);
+            for job in 1..=3 {
+                network.add_edge_with_cost(worker, job + 3, 1, costs[worker - 1][job - 1]);
+            }
+        }
+        network
+    }
+
+    #[test]
+    fn test_min_cost_flow_assignment() {
+        let costs = [[4, 1, 3], [2, 0, 5], [3, 2, 2]];
+        let network = assignment_network(&costs);
+
+        let min_cost_flow = network.min_cost_max_flow(7, 8);
+        assert_eq!(min_cost_flow.value(), 3);
+        assert_eq!(min_cost_flow.cost(), 5);
+
+        let negative_costs = [[-4, -1, -3], [-2, 0, -5], [-3, -2, -2]];
+        let network = assignment_network(&negative_costs);
+        let min_cost_flow = network.min_cost_max_flow(7, 8);
+        assert_eq!(min_cost_flow.value(), 3);
+        assert_eq!(min_cost_flow.cost(), -11);
+    }
+
+    #[test]
+    fn test_min_cost_flow_limit() {
+        let mut network = FlowNetwork::new(4);
+        let cheap = network.add_edge_with_cost(1, 2, 2, 1);
+        network.add_edge_with_cost(2, 4, 2, 1);
+        let expensive = network.add_edge_with_cost(1, 3, 5, 3);
+        network.add_edge_with_cost(3, 4, 5, 3);
+
+        let min_cost_flow = network.min_cost_flow(1, 4, Some(3));
+        assert_eq!((min_cost_flow.value(), min_cost_flow.cost()), (3, 10));
+        assert_eq!((min_cost_flow.flow(cheap), min_cost_flow.flow(expensive)), (2, 1));
+
+        let min_cost_flow = network.min_cost_max_flow(1, 4);
+        assert_eq!((min_cost_flow.value(), min_cost_flow.cost()), (7, 34));
+        assert_eq!(min_cost_flow.value(), network.dinic(1, 4).value());
+    }
+
+    #[test]
+    fn test_min_cost_flow_reroute() {
+        // the first shortest path 1->2->3->4 has to be partly undone through reverse arc
+        let mut network = FlowNetwork::new(4);
+        network.add_edge_with_cost(1, 2, 1, 1);
+        network.add_edge_with_cost(1, 3, 1, 3);
+        network.add_edge_with_cost(2, 3, 1, 1);
+        network.add_edge_with_cost(2, 4, 1, 3);
+        network.add_edge_with_cost(3, 4, 1, 1);
+
+        let min_cost_flow = network.min_cost_max_flow(1, 4);
+        assert_eq!((min_cost_flow.value(), min_cost_flow.cost()), (2, 8));
+        assert_eq!(min_cost_flow.flow(3), 0);
+    }
+
+    #[test]
+    fn test_min_cost_flow_large_cost() {
+        let mut network = FlowNetwork::new(3);
+        network.add_edge_with_cost(1, 2, 100_000, 100_000);
+        network.add_edge_with_cost(2, 3, 100_000, -1);
+
+        let min_cost_flow = network.min_cost_max_flow(1, 3);
+        assert_eq!(min_cost_flow.value(), 100_000);
+        assert_eq!(min_cost_flow.cost(), 9_999_900_000);
+    }
+}
@@ -0,0 +1,505 @@
+use super::Adjacency;
+
+use std::collections::VecDeque;
+use std::fmt;
+
+mod min_cost;
+
+/// flow network with residual reverse arcs
+/// edge `i` (started from 1) is stored as arc `2(i-1)` and its reverse as arc `2(i-1)+1`,
+/// so the reverse of arc `a` is always `a ^ 1`
+pub struct FlowNetwork {
+    num_vertices: usize,
+    /// out vertex of each arc
+    head: Vec<usize>,
+    /// capacity of each arc, 0 for reverse arcs
+    capacity: Vec<i32>,
+    /// cost per unit of flow of each arc, reverse arcs have the opposite cost
+    cost: Vec<i32>,
+    /// same as `first` and `next` in `AdjacencyList` but link arcs
+    first: Vec<i32>,
+    next: Vec<i32>,
+}
+
+/// result of max flow algorithms
+#[derive(Debug)]
+pub struct MaxFlow {
+    value: i32,
+    /// flow on each edge, index 0 is never used
+    flow: Vec<i32>,
+    /// vertices reachable from source in the final residual network
+    source_side: Vec<bool>,
+}
+
+impl MaxFlow {
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    pub fn flow(&self, edge_index: usize) -> i32 {
+        self.flow[edge_index]
+    }
+
+    pub fn is_source_side(&self, vertex: usize) -> bool {
+        self.source_side[vertex]
+    }
+
+    /// source side of a minimum s-t cut, the rest vertices are sink side
+    pub fn source_side(&self) -> Vec<usize> {
+        (1..self.source_side.len()).filter(|&x| self.source_side[x]).collect()
+    }
+}
+
+/// edge whose weight can not be used as capacity
+#[derive(Debug, Clone, PartialEq)]
+pub struct NegativeCapacity {
+    edge_index: usize,
+    capacity: i32,
+}
+
+impl NegativeCapacity {
+    pub fn edge_index(&self) -> usize {
+        self.edge_index
+    }
+
+    pub fn capacity(&self) -> i32 {
+        self.capacity
+    }
+}
+
+impl fmt::Display for NegativeCapacity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "edge {} has negative capacity: {}", self.edge_index, self.capacity)
+    }
+}
+
+impl FlowNetwork {
+    pub fn new(num_vertices: usize) -> FlowNetwork {
+        FlowNetwork {
+            num_vertices,
+            head: Vec::new(),
+            capacity: Vec::new(),
+            cost: Vec::new(),
+            first: vec![-1; num_vertices + 1],
+            next: Vec::new(),
+        }
+    }
+
+    /// use weight of edges as capacities, edges are added in ascending order of index
+    /// and missing edges are skipped, so index of edges are kept unless there are holes,
+    /// see `AdjacencyList::compact`
+    pub fn from_adj_list<A: Adjacency<i32>>(adj_list: &A) -> Result<FlowNetwork, NegativeCapacity> {
+        let mut edges: Vec<_> = (1..=adj_list.num_vertices())
+            .flat_map(|x| adj_list.indexed_edge_iterator_of_vertex(x))
+            .collect();
+        edges.sort_unstable_by_key(|&(edge_index, _)| edge_index);
+
+        let mut network = FlowNetwork::new(adj_list.num_vertices());
+        for (edge_index, edge) in edges {
+            if edge.weight < 0 {
+                return Err(NegativeCapacity { edge_index, capacity: edge.weight });
+            }
+            network.add_edge(edge.in_vertex, edge.out_vertex, edge.weight);
+        }
+        Ok(network)
+    }
+
+    pub fn num_vertices(&self) -> usize {
+        self.num_vertices
+    }
+
+    pub fn num_edges(&self) -> usize {
+        self.head.len() / 2
+    }
+
+    /// add edge and its reverse arc, return index of the edge
+    pub fn add_edge(&mut self, in_vertex: usize, out_vertex: usize, capacity: i32) -> usize {
+        self.add_edge_with_cost(in_vertex, out_vertex, capacity, 0)
+    }
+
+    /// add edge with cost per unit of flow used by min cost flow, return index of the edge
+    pub fn add_edge_with_cost(&mut self, in_vertex: usize, out_vertex: usize, capacity: i32, cost: i32) -> usize {
+        if in_vertex == 0 || out_vertex == 0 || in_vertex > self.num_vertices || out_vertex > self.num_vertices {
+            panic!("numerical order of vertex must be in 1..={}!", self.num_vertices);
+        }
+        if capacity < 0 {
+            panic!("capacity can not be negative!");
+        }
+
+        self.add_arc(in_vertex, out_vertex, capacity, cost);
+        self.add_arc(out_vertex, in_vertex, 0, -cost);
+
+        self.num_edges()
+    }
+
+    fn add_arc(&mut self, in_vertex: usize, out_vertex: usize, capacity: i32, cost: i32) {
+        self.head.push(out_vertex);
+        self.capacity.push(capacity);
+        self.cost.push(cost);
+        self.next.push(self.first[in_vertex]);
+        self.first[in_vertex] = (self.head.len() - 1) as i32;
+    }
+
+    /// vertex the arc comes from
+    fn tail(&self, arc: usize) -> usize {
+        self.head[arc ^ 1]
+    }
+
+    /// flow on each edge given residual capacities, index 0 is never used
+    fn edge_flows(&self, residual: &[i32]) -> Vec<i32> {
+        let mut flow = vec![0; self.num_edges() + 1];
+        for (edge_index, edge_flow) in flow.iter_mut().enumerate().skip(1) {
+            let arc = 2 * (edge_index - 1);
+            *edge_flow = self.capacity[arc] - residual[arc];
+        }
+        flow
+    }
+
+    fn max_flow(&self, source: usize, residual: Vec<i32>) -> MaxFlow {
+        let flow = self.edge_flows(&residual);
+
+        let mut source_side = vec![false; self.num_vertices + 1];
+        let mut queue = VecDeque::new();
+        source_side[source] = true;
+        queue.push_back(source);
+        while let Some(vertex) = queue.pop_front() {
+            let mut arc = self.first[vertex];
+            while arc != -1 {
+                let arc_index = arc as usize;
+                if residual[arc_index] > 0 && !source_side[self.head[arc_index]] {
+                    source_side[self.head[arc_index]] = true;
+                    queue.push_back(self.head[arc_index]);
+                }
+                arc = self.next[arc_index];
+            }
+        }
+
+        let value = (1..=self.num_edges())
+            .map(|edge_index| {
+                let arc = 2 * (edge_index - 1);
+                match (self.tail(arc) == source, self.head[arc] == source) {
+                    (true, false) => flow[edge_index],
+                    (false, true) => -flow[edge_index],
+                    _ => 0,
+                }
+            })
+            .sum();
+
+        MaxFlow {
+            value,
+            flow,
+            source_side,
+        }
+    }
+
+    /// [Dinic](https://en.wikipedia.org/wiki/Dinic%27s_algorithm),
+    /// O(V^2 E) time, augment blocking flows on level graph found by bfs
+    pub fn dinic(&self, source: usize, sink: usize) -> MaxFlow {
+        let mut residual = self.capacity.clone();
+        if source == sink {
+            return self.max_flow(source, residual);
+        }
+
+        // distance from source in residual network, `None` for unreachable and dead ends
+        let mut level: Vec<Option<usize>> = vec![None; self.num_vertices + 1];
+        // next arc to try for each vertex
+        let mut current_arc = vec![-1; self.num_vertices + 1];
+        let mut queue = VecDeque::with_capacity(self.num_vertices);
+        let mut path: Vec<usize> = Vec::with_capacity(self.num_vertices);
+
+        loop {
+            level.iter_mut().for_each(|x| *x = None);
+            level[source] = Some(0);
+            queue.push_back(source);
+            while let Some(vertex) = queue.pop_front() {
+                let mut arc = self.first[vertex];
+                while arc != -1 {
+                    let arc_index = arc as usize;
+                    let next_vertex = self.head[arc_index];
+                    if residual[arc_index] > 0 && level[next_vertex].is_none() {
+                        level[next_vertex] = Some(level[vertex].unwrap() + 1);
+                        queue.push_back(next_vertex);
+                    }
+                    arc = self.next[arc_index];
+                }
+            }
+            if level[sink].is_none() {
+                break;
+            }
+
+            current_arc.copy_from_slice(&self.first);
+            let mut vertex = source;
+            loop {
+                if vertex == sink {
+                    let bottleneck = path.iter().map(|&x| residual[x]).min().unwrap();
+                    for &arc in path.iter() {
+                        residual[arc] -= bottleneck;
+                        residual[arc ^ 1] += bottleneck;
+                    }
+                    path.clear();
+                    vertex = source;
+                    continue;
+                }
+
+                // skip arcs not in level graph or saturated
+                while current_arc[vertex] != -1 {
+                    let arc_index = current_arc[vertex] as usize;
+                    if residual[arc_index] > 0 && level[self.head[arc_index]] == level[vertex].map(|x| x + 1) {
+                        break;
+                    }
+                    current_arc[vertex] = self.next[arc_index];
+                }
+
+                match current_arc[vertex] {
+                    -1 => {
+                        // dead end, retreat
+                        if vertex == source {
+                            break;
+                        }
+                        level[vertex] = None;
+                        let arc = path.pop().unwrap();
+                        vertex = self.tail(arc);
+                        current_arc[vertex] = self.next[arc];
+                    },
+                    arc => {
+                        path.push(arc as usize);
+                        vertex = self.head[arc as usize];
+                    }
+                }
+            }
+        }
+
+        self.max_flow(source, residual)
+    }
+
+    /// highest label [push-relabel](https://en.wikipedia.org/wiki/Push%E2%80%93relabel_maximum_flow_algorithm)
+    /// with gap heuristic, O(V^2 sqrt(E)) time
+    pub fn push_relabel(&self, source: usize, sink: usize) -> MaxFlow {
+        let mut residual = self.capacity.clone();
+        if source == sink {
+            return self.max_flow(source, residual);
+        }
+
+        let num_vertices = self.num_vertices;
+        // a vertex with excess can always reach source, so its height never exceeds 2V - 1
+        let max_height = 2 * num_vertices;
+        let mut height = vec![0; num_vertices + 1];
+        let mut excess = vec![0; num_vertices + 1];
+        // number of vertices with each height, used by gap heuristic
+        let mut count = vec![0; max_height + 1];
+        // active vertices of each height, height of a vertex may be raised after it is added
+        let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); max_height + 1];
+        let mut highest = 0;
+        // next arc to try for each vertex
+        let mut current_arc = self.first.clone();
+
+        height[source] = num_vertices;
+        count[0] = num_vertices - 1;
+        count[num_vertices] = 1;
+
+        // saturate all arcs from source
+        let mut arc = self.first[source];
+        while arc != -1 {
+            let arc_index = arc as usize;
+            let next_vertex = self.head[arc_index];
+            let delta = residual[arc_index];
+            residual[arc_index] -= delta;
+            residual[arc_index ^ 1] += delta;
+            excess[source] -= delta;
+            excess[next_vertex] += delta;
+            if delta > 0 && next_vertex != sink && next_vertex != source && excess[next_vertex] == delta {
+                buckets[0].push(next_vertex);
+            }
+            arc = self.next[arc_index];
+        }
+
+        loop {
+            while highest > 0 && buckets[highest].is_empty() {
+                highest -= 1;
+            }
+            let vertex = match buckets[highest].pop() {
+                Some(x) => x,
+                None => break,
+            };
+            if height[vertex] != highest {
+                // raised by gap heuristic
+                buckets[height[vertex]].push(vertex);
+                highest = height[vertex];
+                continue;
+            }
+
+            // discharge
+            while excess[vertex] > 0 {
+                if current_arc[vertex] == -1 {
+                    // relabel to one more than the lowest neighbour in residual network
+                    let old_height = height[vertex];
+                    let mut new_height = max_height;
+                    let mut arc = self.first[vertex];
+                    while arc != -1 {
+                        let arc_index = arc as usize;
+                        if residual[arc_index] > 0 {
+                            new_height = new_height.min(height[self.head[arc_index]] + 1);
+                        }
+                        arc = self.next[arc_index];
+                    }
+                    count[old_height] -= 1;
+                    count[new_height] += 1;
+                    height[vertex] = new_height;
+                    current_arc[vertex] = self.first[vertex];
+
+                    // gap, vertices above the empty height can not reach sink any more
+                    if count[old_height] == 0 && old_height < num_vertices {
+                        for other in 1..=num_vertices {
+                            if other != source && height[other] > old_height && height[other] < num_vertices {
+                                count[height[other]] -= 1;
+                                count[num_vertices + 1] += 1;
+                                height[other] = num_vertices + 1;
+                                current_arc[other] = self.first[other];
+                            }
+                        }
+                    }
+                    continue;
+                }
+
+                let arc_index = current_arc[vertex] as usize;
+                let next_vertex = self.head[arc_index];
+                if residual[arc_index] > 0 && height[vertex] == height[next_vertex] + 1 {
+                    let delta = residual[arc_index].min(excess[vertex]);
+                    residual[arc_index] -= delta;
+                    residual[arc_index ^ 1] += delta;
+                    excess[vertex] -= delta;
+                    excess[next_vertex] += delta;
+                    if next_vertex != source && next_vertex != sink && excess[next_vertex] == delta {
+                        buckets[height[next_vertex]].push(next_vertex);
+                    }
+                } else {
+                    current_arc[vertex] = self.next[arc_index];
+                }
+            }
+            highest = height[vertex];
+        }
+
+        self.max_flow(source, residual)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::csr::CompressedSparseRow;
+    use crate::graph::{load_adj_list_from_file, AdjacencyList};
+
+    const POSITIVE_SIMPLE_GRAPH_FILE: &str = "src/graph/examples/positive_simple.txt";
+
+    /// network in chapter 26 of introduction to algorithms
+    fn clrs_network() -> FlowNetwork {
+        let mut network = FlowNetwork::new(6);
+        network.add_edge(1, 2, 16);
+        network.add_edge(1, 3, 13);
+        network.add_edge(2, 3, 10);
+        network.add_edge(3, 2, 4);
+        network.add_edge(2, 4, 12);
+        network.add_edge(4, 3, 9);
+        network.add_edge(3, 5, 14);
+        network.add_edge(5, 4, 7);
+        network.add_edge(4, 6, 20);
+        network.add_edge(5, 6, 4);
+        network
+    }
+
+    /// check capacity constraint, flow conservation and that cut capacity equals flow value
+    fn assert_max_flow(network: &FlowNetwork, max_flow: &MaxFlow, source: usize, sink: usize) {
+        let mut balance = vec![0; network.num_vertices() + 1];
+        let mut cut_capacity = 0;
+        for edge_index in 1..=network.num_edges() {
+            let arc = 2 * (edge_index - 1);
+            let (in_vertex, out_vertex) = (network.tail(arc), network.head[arc]);
+            let flow = max_flow.flow(edge_index);
+            assert!(flow >= 0 && flow <= network.capacity[arc]);
+            balance[in_vertex] -= flow;
+            balance[out_vertex] += flow;
+            if max_flow.is_source_side(in_vertex) && !max_flow.is_source_side(out_vertex) {
+                cut_capacity += network.capacity[arc];
+            }
+        }
+
+        for vertex in 1..=network.num_vertices() {
+            match vertex {
+                x if x == source => assert_eq!(balance[x], -max_flow.value()),
+                x if x == sink => assert_eq!(balance[x], max_flow.value()),
+                x => assert_eq!(balance[x], 0),
+            }
+        }
+        assert!(max_flow.is_source_side(source));
+        assert!(!max_flow.is_source_side(sink));
+        assert_eq!(cut_capacity, max_flow.value());
+    }
+
+    #[test]
+    fn test_max_flow() {
+        let network = clrs_network();
+        for max_flow in [network.dinic(1, 6), network.push_relabel(1, 6)].iter() {
+            assert_eq!(max_flow.value(), 23);
+            assert_max_flow(&network, max_flow, 1, 6);
+            assert_eq!(max_flow.source_side(), vec![1, 2, 3, 5]);
+        }
+    }
+
+    #[test]
+    fn test_max_flow_unreachable_sink() {
+        let mut network = FlowNetwork::new(4);
+        network.add_edge(1, 2, 5);
+        network.add_edge(2, 3, 5);
+        network.add_edge(4, 3, 5);
+        for max_flow in [network.dinic(1, 4), network.push_relabel(1, 4)].iter() {
+            assert_eq!(max_flow.value(), 0);
+            assert_max_flow(&network, max_flow, 1, 4);
+            assert_eq!(max_flow.source_side(), vec![1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn test_dinic_same_as_push_relabel() {
+        let adj_list = load_adj_list_from_file(POSITIVE_SIMPLE_GRAPH_FILE);
+        let network = FlowNetwork::from_adj_list(&adj_list).unwrap();
+        assert_eq!(network.num_edges(), adj_list.num_edges());
+
+        for &(source, sink) in [(1, 1000), (17, 3), (500, 499)].iter() {
+            let dinic = network.dinic(source, sink);
+            let push_relabel = network.push_relabel(source, sink);
+            assert_eq!(dinic.value(), push_relabel.value());
+            assert_max_flow(&network, &dinic, source, sink);
+            assert_max_flow(&network, &push_relabel, source, sink);
+        }
+    }
+
+    #[test]
+    fn test_from_adj_list() {
+        let mut adj_list = AdjacencyList::new(3, 3);
+        adj_list.add_directed_edge(1, 2, 4);
+        adj_list.add_directed_edge(1, 3, 2);
+        adj_list.add_directed_edge(2, 3, 3);
+        adj_list.remove_edge(2);
+        let network = FlowNetwork::from_adj_list(&adj_list).unwrap();
+        assert_eq!(network.num_edges(), 2);
+        assert_eq!(network.dinic(1, 3).value(), 3);
+
+        adj_list.add_directed_edge(3, 1, -1);
+        match FlowNetwork::from_adj_list(&adj_list) {
+            Err(error) => assert_eq!((error.edge_index(), error.capacity()), (4, -1)),
+            Ok(_) => panic!("negative capacity is not detected!"),
+        }
+
+        // the same edges in ascending order of index from any adjacency
+        let csr = CompressedSparseRow::from_adj_list(&adj_list);
+        match FlowNetwork::from_adj_list(&csr) {
+            Err(error) => assert_eq!((error.edge_index(), error.capacity()), (4, -1)),
+            Ok(_) => panic!("negative capacity is not detected!"),
+        }
+        adj_list.remove_edge(4);
+        let network = FlowNetwork::from_adj_list(&CompressedSparseRow::from_adj_list(&adj_list)).unwrap();
+        assert_eq!(network.num_edges(), 2);
+        assert_eq!(network.dinic(1, 3).value(), 3);
+    }
+}
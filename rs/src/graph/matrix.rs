@@ -0,0 +1,310 @@
+use super::{Adjacency, AdjacencyList, Edge, Weight};
+
+use std::fs;
+use std::iter::Map;
+
+/// edges in a row or a column of `AdjacencyMatrix`
+pub struct MatrixEdgeIterator<'a, W = i32> {
+    cells: &'a [Option<W>],
+    num_vertices: usize,
+    /// position of next cell, index of edge is position + 1
+    position: usize,
+    end: usize,
+    step: usize,
+}
+
+impl<'a, W: Weight> Iterator for MatrixEdgeIterator<'a, W> {
+    type Item = (usize, Edge<W>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.position < self.end {
+            let position = self.position;
+            self.position += self.step;
+            if let Some(weight) = self.cells[position] {
+                let edge = Edge::new(position / self.num_vertices + 1, position % self.num_vertices + 1, weight);
+                return Some((position + 1, edge));
+            }
+        }
+        None
+    }
+}
+
+pub type MatrixOutgoingEdgeIterator<'a, W> = Map<MatrixEdgeIterator<'a, W>, fn((usize, Edge<W>)) -> Edge<W>>;
+
+/// [adjacency matrix](https://en.wikipedia.org/wiki/Adjacency_matrix) for dense graphs,
+/// there is at most one edge from a vertex to another one,
+/// index of edge from `u` to `v` is `(u - 1) * num_vertices + v`, so the two directed edges
+/// of an undirected edge are not adjacent as in `AdjacencyList`
+pub struct AdjacencyMatrix<W = i32> {
+    num_vertices: usize,
+    /// row major weights, cell of edge is its index minus 1 and its vertices are given by the cell
+    cells: Vec<Option<W>>,
+    num_existing_edges: usize,
+}
+
+impl<W: Weight> AdjacencyMatrix<W> {
+    pub fn new(num_vertices: usize) -> AdjacencyMatrix<W> {
+        let num_cells = num_vertices
+            .checked_mul(num_vertices)
+            .unwrap_or_else(|| panic!("too many vertices for adjacency matrix: {}!", num_vertices));
+        AdjacencyMatrix {
+            num_vertices,
+            cells: vec![None; num_cells],
+            num_existing_edges: 0,
+        }
+    }
+
+    /// parallel edges are merged into the one with smallest weight
+    pub fn from_adj_list(adj_list: &AdjacencyList<W>) -> AdjacencyMatrix<W> {
+        let mut matrix = AdjacencyMatrix::new(adj_list.num_vertices());
+        for edge in adj_list.edges.iter().flatten() {
+            match matrix.weight(edge.in_vertex, edge.out_vertex) {
+                Some(x) if x <= edge.weight => {},
+                _ => {
+                    matrix.set_edge(edge.in_vertex, edge.out_vertex, edge.weight);
+                },
+            }
+        }
+        matrix
+    }
+
+    /// edges are added in ascending order of their index in matrix,
+    /// so index of edge in `AdjacencyList` is its rank among existing edges
+    pub fn to_adj_list(&self) -> AdjacencyList<W> {
+        let mut adj_list = AdjacencyList::new(self.num_vertices, self.num_existing_edges);
+        for vertex in 1..=self.num_vertices {
+            for (_, edge) in self.row(vertex) {
+                adj_list.add_directed_edge(edge.in_vertex, edge.out_vertex, edge.weight);
+            }
+        }
+        adj_list
+    }
+
+    pub fn num_vertices(&self) -> usize {
+        self.num_vertices
+    }
+
+    /// upper bound of index of edge, which is `num_vertices * num_vertices`
+    pub fn num_edges(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn num_existing_edges(&self) -> usize {
+        self.num_existing_edges
+    }
+
+    /// index of edge from `in_vertex` to `out_vertex`, whether or not it exists
+    pub fn edge_index(&self, in_vertex: usize, out_vertex: usize) -> usize {
+        if in_vertex == 0 || out_vertex == 0 {
+            panic!("numerical order of vertex can not be less than 0!");
+        }
+        if in_vertex > self.num_vertices || out_vertex > self.num_vertices {
+            panic!("numerical order of vertex can not be greater than {}!", self.num_vertices);
+        }
+        (in_vertex - 1) * self.num_vertices + out_vertex
+    }
+
+    pub fn contains_edge(&self, in_vertex: usize, out_vertex: usize) -> bool {
+        self.cells[self.edge_index(in_vertex, out_vertex) - 1].is_some()
+    }
+
+    /// weight of edge from `in_vertex` to `out_vertex` in O(1)
+    pub fn weight(&self, in_vertex: usize, out_vertex: usize) -> Option<W> {
+        self.cells[self.edge_index(in_vertex, out_vertex) - 1]
+    }
+
+    /// add or replace edge, return weight of the replaced one
+    pub fn set_edge(&mut self, in_vertex: usize, out_vertex: usize, weight: W) -> Option<W> {
+        let edge_index = self.edge_index(in_vertex, out_vertex);
+        let old_weight = self.cells[edge_index - 1].replace(weight);
+        if old_weight.is_none() {
+            self.num_existing_edges += 1;
+        }
+        old_weight
+    }
+
+    /// set edges of both directions
+    pub fn set_undirected_edge(&mut self, in_vertex: usize, out_vertex: usize, weight: W) {
+        self.set_edge(in_vertex, out_vertex, weight);
+        self.set_edge(out_vertex, in_vertex, weight);
+    }
+
+    /// return weight of the removed edge
+    pub fn remove_edge(&mut self, in_vertex: usize, out_vertex: usize) -> Option<W> {
+        let edge_index = self.edge_index(in_vertex, out_vertex);
+        let old_weight = self.cells[edge_index - 1].take();
+        if old_weight.is_some() {
+            self.num_existing_edges -= 1;
+        }
+        old_weight
+    }
+
+    /// get edge by its index, index of edge is started from 1
+    pub fn edge(&self, edge_index: usize) -> Edge<W> {
+        let position = edge_index.wrapping_sub(1);
+        match self.cells.get(position) {
+            Some(&Some(weight)) => Edge::new(position / self.num_vertices + 1, position % self.num_vertices + 1, weight),
+            _ => panic!("edge with index {} does not exist!", edge_index),
+        }
+    }
+
+    /// cells of `row`, vertex 0 has no edges
+    fn row(&self, vertex: usize) -> MatrixEdgeIterator<'_, W> {
+        let position = vertex.saturating_sub(1) * self.num_vertices;
+        MatrixEdgeIterator {
+            cells: &self.cells,
+            num_vertices: self.num_vertices,
+            position,
+            end: if vertex == 0 { position } else { position + self.num_vertices },
+            step: 1,
+        }
+    }
+
+    fn column(&self, vertex: usize) -> MatrixEdgeIterator<'_, W> {
+        MatrixEdgeIterator {
+            cells: &self.cells,
+            num_vertices: self.num_vertices,
+            position: vertex.saturating_sub(1),
+            end: if vertex == 0 { 0 } else { self.cells.len() },
+            step: self.num_vertices.max(1),
+        }
+    }
+}
+
+impl<W: Weight> Adjacency<W> for AdjacencyMatrix<W> {
+    type EdgeIter<'a> = MatrixOutgoingEdgeIterator<'a, W> where W: 'a;
+    type IndexedEdgeIter<'a> = MatrixEdgeIterator<'a, W> where W: 'a;
+
+    fn num_vertices(&self) -> usize {
+        self.num_vertices
+    }
+
+    fn num_edges(&self) -> usize {
+        self.num_edges()
+    }
+
+    /// O(V) for every vertex
+    fn edge_iterator_of_vertex(&self, vertex: usize) -> MatrixOutgoingEdgeIterator<'_, W> {
+        self.row(vertex).map(|(_, edge)| edge)
+    }
+
+    fn indexed_edge_iterator_of_vertex(&self, vertex: usize) -> MatrixEdgeIterator<'_, W> {
+        self.row(vertex)
+    }
+
+    fn incoming_edge_iterator_of_vertex(&self, vertex: usize) -> MatrixEdgeIterator<'_, W> {
+        self.column(vertex)
+    }
+
+    fn edge(&self, edge_index: usize) -> Edge<W> {
+        self.edge(edge_index)
+    }
+}
+
+
+/// same format as `load_adj_list_from_file`, parallel edges are merged into the one with smallest weight
+pub fn load_adj_matrix_from_file<W: Weight>(file_name: &str) -> AdjacencyMatrix<W> {
+    let content = fs::read_to_string(file_name)
+        .unwrap_or_else(|_| panic!("fail to read file: `{}`", file_name));
+
+    let mut lines = content.lines();
+
+    let first_line: Vec<&str> = lines.next().unwrap().split_ascii_whitespace().collect();
+    let num_vertices = first_line[0].parse::<usize>().unwrap();
+
+    let mut matrix = AdjacencyMatrix::new(num_vertices);
+    for line in lines {
+        let line: Vec<&str> = line.split_ascii_whitespace().collect();
+        let in_vertex = line[0].parse::<usize>().unwrap();
+        let out_vertex = line[1].parse::<usize>().unwrap();
+        let weight = match line[2].parse::<W>() {
+            Ok(x) => x,
+            Err(_) => panic!("fail to parse weight: `{}`!", line[2]),
+        };
+
+        match matrix.weight(in_vertex, out_vertex) {
+            Some(x) if x <= weight => {},
+            _ => {
+                matrix.set_edge(in_vertex, out_vertex, weight);
+            },
+        }
+    }
+
+    matrix
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::algorithms::Graph;
+    use crate::graph::load_adj_list_from_file;
+
+    const TEST_GRAPH_FILE: &str = "src/graph/examples/test.txt";
+    const POSITIVE_SIMPLE_GRAPH_FILE: &str = "src/graph/examples/positive_simple.txt";
+
+    #[test]
+    fn test_adj_matrix() {
+        let mut matrix: AdjacencyMatrix = AdjacencyMatrix::new(3);
+        assert_eq!(matrix.set_edge(1, 2, 4), None);
+        assert_eq!(matrix.set_edge(1, 2, 3), Some(4));
+        matrix.set_undirected_edge(2, 3, 7);
+        assert_eq!(matrix.num_existing_edges(), 3);
+        assert_eq!(matrix.weight(1, 2), Some(3));
+        assert_eq!(matrix.weight(2, 1), None);
+        assert!(matrix.contains_edge(3, 2));
+
+        assert_eq!(matrix.edge_index(2, 3), 6);
+        assert_eq!(matrix.edge(6).out_vertex(), 3);
+        let incoming: Vec<usize> = matrix.incoming_edge_iterator_of_vertex(2).map(|(x, _)| x).collect();
+        assert_eq!(incoming, vec![2, 8]);
+        assert_eq!(matrix.edge_iterator_of_vertex(2).count(), 1);
+        assert!(matrix.indexed_edge_iterator_of_vertex(0).next().is_none());
+
+        assert_eq!(matrix.remove_edge(1, 2), Some(3));
+        assert_eq!(matrix.remove_edge(1, 2), None);
+        assert_eq!(matrix.num_existing_edges(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "too many vertices")]
+    fn test_adj_matrix_too_many_vertices() {
+        let _: AdjacencyMatrix = AdjacencyMatrix::new(usize::MAX / 2);
+    }
+
+    #[test]
+    fn test_adj_matrix_conversion() {
+        let mut adj_list: AdjacencyList = load_adj_list_from_file(TEST_GRAPH_FILE);
+        adj_list.add_directed_edge(1, 2, 1);
+        adj_list.add_directed_edge(1, 2, 6);
+        let matrix = AdjacencyMatrix::from_adj_list(&adj_list);
+        assert_eq!(matrix.num_existing_edges(), 5);
+        assert_eq!(matrix.weight(1, 2), Some(1));
+        assert_eq!(matrix.weight(3, 4), Some(-2));
+
+        let adj_list = matrix.to_adj_list();
+        assert_eq!(adj_list.num_edges(), 5);
+        let edges: Vec<(usize, usize, i32)> = (1..=5)
+            .map(|x| adj_list.edge(x))
+            .map(|edge| (edge.in_vertex, edge.out_vertex, edge.weight))
+            .collect();
+        assert_eq!(edges, vec![(1, 2, 1), (1, 4, 8), (2, 3, 5), (2, 4, 9), (3, 4, -2)]);
+    }
+
+    #[test]
+    fn test_graph_algorithms_on_adj_matrix() {
+        // random graph may contain parallel edges, merging them does not change distances
+        let from_list: Graph = Graph::create_from_file(POSITIVE_SIMPLE_GRAPH_FILE);
+        let from_matrix = Graph::new(load_adj_matrix_from_file::<i32>(POSITIVE_SIMPLE_GRAPH_FILE));
+        for source in (1..=from_list.adj_list().num_vertices()).step_by(331) {
+            assert_eq!(from_matrix.dijkstra(source).distances(), from_list.dijkstra(source).distances());
+        }
+
+        let from_matrix = Graph::new(load_adj_matrix_from_file::<i32>(TEST_GRAPH_FILE));
+        let matrix = from_matrix.floyd_warshall().unwrap();
+        assert_eq!(matrix.distance(1, 4), Some(7));
+        let path = matrix.path(1, 4).unwrap();
+        assert_eq!(path.vertices(), &[1, 2, 3, 4]);
+        assert_eq!(path.edges().iter().map(|&x| from_matrix.adj_list().edge(x).weight()).sum::<i32>(), 7);
+    }
+}
@@ -1,18 +1,42 @@
 use std::fmt;
 use std::fs;
+use std::iter::{Cloned, Map};
 
+#[macro_use]
+mod visitor;
 mod algorithms;
+mod all_pairs;
+mod assignment;
+mod biconnectivity;
+mod bipartite;
+mod builder;
+mod coloring;
+mod csr;
+mod eulerian;
+mod flow;
+mod matrix;
+mod mst;
+mod point_to_point;
+mod scc;
+mod shortest_path;
+mod topological;
+mod traversal;
 mod utils;
+mod weight;
 
+pub use self::shortest_path::ShortestPaths;
+pub use self::weight::Weight;
 
-pub struct Edge {
+
+#[derive(Clone)]
+pub struct Edge<W = i32> {
     in_vertex: usize,
     out_vertex: usize,
-    weight: i32,
+    weight: W,
 }
 
-impl Edge {
-    pub fn new(in_vertex: usize, out_vertex: usize, weight: i32) -> Edge {
+impl<W: Weight> Edge<W> {
+    pub fn new(in_vertex: usize, out_vertex: usize, weight: W) -> Edge<W> {
         Edge {
             in_vertex,
             out_vertex,
@@ -20,25 +44,37 @@ impl Edge {
         }
     }
 
+    pub fn in_vertex(&self) -> usize {
+        self.in_vertex
+    }
+
+    pub fn out_vertex(&self) -> usize {
+        self.out_vertex
+    }
+
+    pub fn weight(&self) -> W {
+        self.weight
+    }
+
     pub fn to_str(&self) -> String {
         format!("in vertex: {}, out vertex: {}, weight: {}", self.in_vertex, self.out_vertex, self.weight)
     }
 }
 
-impl fmt::Display for Edge {
+impl<W: Weight> fmt::Display for Edge<W> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "in vertex: {}, out vertex: {}, weight: {}", self.in_vertex, self.out_vertex, self.weight)
     }
 }
 
 
-pub struct EdgeIterator<'a> {
-    graph: &'a AdjacencyList,
+pub struct EdgeIterator<'a, W = i32> {
+    graph: &'a AdjacencyList<W>,
     next_edge_index: i32,
 }
 
-impl<'a> Iterator for EdgeIterator<'a> {
-    type Item = &'a Edge;
+impl<'a, W> Iterator for EdgeIterator<'a, W> {
+    type Item = &'a Edge<W>;
     
     fn next(&mut self) -> Option<Self::Item> {
         match self.next_edge_index {
@@ -54,35 +90,95 @@ impl<'a> Iterator for EdgeIterator<'a> {
     }
 }
 
+/// same as `EdgeIterator` but also yields the index of each edge,
+/// `next` decides whether outgoing or incoming edges are followed
+pub struct IndexedEdgeIterator<'a, W = i32> {
+    edges: &'a [Option<Edge<W>>],
+    next: &'a [i32],
+    next_edge_index: i32,
+}
+
+impl<'a, W> Iterator for IndexedEdgeIterator<'a, W> {
+    type Item = (usize, &'a Edge<W>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_edge_index {
+            -1 => None,
+            x if x > 0 => {
+                let current_edge_index = x as usize;
+                self.next_edge_index = self.next[current_edge_index];
+                self.edges[current_edge_index]
+                    .as_ref()
+                    .map(|edge| (current_edge_index, edge))
+            },
+            _ => panic!("Index of edge is not allowed!")
+        }
+    }
+}
+
+/// read-only view of a graph shared by its representations, algorithms of `Graph` are built on it,
+/// index of vertex and edge are started from 1.
+/// edges are yielded by value, so a representation such as `AdjacencyMatrix` does not have to store them
+pub trait Adjacency<W> {
+    type EdgeIter<'a>: Iterator<Item = Edge<W>> where Self: 'a, W: 'a;
+    type IndexedEdgeIter<'a>: Iterator<Item = (usize, Edge<W>)> where Self: 'a, W: 'a;
+
+    fn num_vertices(&self) -> usize;
+
+    /// upper bound of index of edge
+    fn num_edges(&self) -> usize;
+
+    /// edges whose in vertex is `vertex`
+    fn edge_iterator_of_vertex(&self, vertex: usize) -> Self::EdgeIter<'_>;
+
+    /// same as `edge_iterator_of_vertex` but also yields the index of each edge
+    fn indexed_edge_iterator_of_vertex(&self, vertex: usize) -> Self::IndexedEdgeIter<'_>;
+
+    /// edges whose out vertex is `vertex`
+    fn incoming_edge_iterator_of_vertex(&self, vertex: usize) -> Self::IndexedEdgeIter<'_>;
+
+    fn edge(&self, edge_index: usize) -> Edge<W>;
+}
+
+
 /// more info can be found in [邻接表](https://wiki.jikexueyuan.com/project/easy-learn-algorithm/clever-adjacency-list.html)
-pub struct AdjacencyList {
+pub struct AdjacencyList<W = i32> {
     /// suppose the index of vertex and edge are started from 1 but not 0
-    edges: Vec<Option<Edge>>,
+    edges: Vec<Option<Edge<W>>>,
     first: Vec<i32>,
     next: Vec<i32>,
+    /// same as `first` and `next` but link incoming edges of each vertex
+    first_in: Vec<i32>,
+    next_in: Vec<i32>,
 
     num_vertices: usize,
+    /// number of slots for edges, it grows when more edges than estimated are added
     num_edges: usize,
 
-    /// current number of edges in AdjacencyList
+    /// largest index of edge which has been added
     _current_num_edges: usize,
+    /// removed edges leave holes so that indices of the other edges are stable
+    num_removed_edges: usize,
 }
 
-impl AdjacencyList {
-    pub fn new(num_vertices: usize, num_edges: usize) -> AdjacencyList {
+impl<W: Weight> AdjacencyList<W> {
+    pub fn new(num_vertices: usize, num_edges: usize) -> AdjacencyList<W> {
         AdjacencyList {
             edges: {
                 let mut edges = Vec::with_capacity(num_edges+1);
-                edges.resize_with(num_edges+1, || None::<Edge>);
+                edges.resize_with(num_edges+1, || None::<Edge<W>>);
                 edges
             },
             first: vec![-1; num_vertices+1 as usize],
             next: vec![-1; num_edges+1 as usize],
+            first_in: vec![-1; num_vertices + 1],
+            next_in: vec![-1; num_edges + 1],
 
             num_vertices,
             num_edges,
 
             _current_num_edges: 0,
+            num_removed_edges: 0,
         }
     }
 
@@ -90,11 +186,27 @@ impl AdjacencyList {
         self.num_vertices
     }
 
+    /// number of slots for edges, which is also the upper bound of index of edge,
+    /// it may be larger than the number of existing edges
     pub fn num_edges(&self) -> usize {
         self.num_edges
     }
 
-    pub fn add_directed_edge(&mut self, in_vertex: usize, out_vertex: usize, weight: i32) {
+    /// number of edges which have been added and not removed
+    pub fn num_existing_edges(&self) -> usize {
+        self._current_num_edges - self.num_removed_edges
+    }
+
+    /// add a vertex without edges and return its index
+    pub fn add_vertex(&mut self) -> usize {
+        self.first.push(-1);
+        self.first_in.push(-1);
+        self.num_vertices += 1;
+        self.num_vertices
+    }
+
+    /// return index of the new edge, the slots of edges are doubled if they are full
+    pub fn add_directed_edge(&mut self, in_vertex: usize, out_vertex: usize, weight: W) -> usize {
         if in_vertex <= 0 || out_vertex <= 0 {
             panic!("numerical order of vertex can not be less than 0!");
         }
@@ -102,34 +214,196 @@ impl AdjacencyList {
             panic!("numerical order of vertex can not be greater than {}!", self.num_vertices);
         } 
         if self._current_num_edges >= self.num_edges {
-            panic!("can not add edge to AdjacencyList because it has already full!");
+            self.reserve_edges(self.num_edges.max(1));
         }
         self._current_num_edges += 1;
 
         self.edges[self._current_num_edges] = Some(Edge::new(in_vertex, out_vertex, weight));
         self.next[self._current_num_edges] = self.first[in_vertex];
         self.first[in_vertex] = self._current_num_edges as i32;
+        self.next_in[self._current_num_edges] = self.first_in[out_vertex];
+        self.first_in[out_vertex] = self._current_num_edges as i32;
+
+        self._current_num_edges
     }
 
-    pub fn add_undirected_edge(&mut self, in_vertex: usize, out_vertex: usize, weight: i32) {
-        self.add_directed_edge(in_vertex, out_vertex, weight);
+    /// return index of the first of the two directed edges, the other one is next to it
+    pub fn add_undirected_edge(&mut self, in_vertex: usize, out_vertex: usize, weight: W) -> usize {
+        let edge_index = self.add_directed_edge(in_vertex, out_vertex, weight);
         self.add_directed_edge(out_vertex, in_vertex, weight);
+        edge_index
+    }
+
+    fn reserve_edges(&mut self, additional: usize) {
+        self.num_edges += additional;
+        self.edges.resize_with(self.num_edges + 1, || None);
+        self.next.resize(self.num_edges + 1, -1);
+        self.next_in.resize(self.num_edges + 1, -1);
+    }
+
+    pub fn contains_edge(&self, edge_index: usize) -> bool {
+        edge_index > 0 && matches!(self.edges.get(edge_index), Some(Some(_)))
+    }
+
+    /// remove edge in O(degree), indices of the other edges are not changed,
+    /// use `compact` to reclaim the holes left by removed edges
+    pub fn remove_edge(&mut self, edge_index: usize) -> Edge<W> {
+        if !self.contains_edge(edge_index) {
+            panic!("edge with index {} does not exist!", edge_index);
+        }
+        let edge = self.edges[edge_index].take().unwrap();
+        Self::unlink(&mut self.first[edge.in_vertex], &mut self.next, edge_index);
+        Self::unlink(&mut self.first_in[edge.out_vertex], &mut self.next_in, edge_index);
+        self.num_removed_edges += 1;
+
+        edge
+    }
+
+    /// remove `edge_index` from the chain started by `head`
+    fn unlink(head: &mut i32, next: &mut [i32], edge_index: usize) {
+        let edge_index = edge_index as i32;
+        if *head == edge_index {
+            *head = next[edge_index as usize];
+        } else {
+            let mut current = *head;
+            while next[current as usize] != edge_index {
+                current = next[current as usize];
+            }
+            next[current as usize] = next[edge_index as usize];
+        }
+        next[edge_index as usize] = -1;
+    }
+
+    /// remove vertex and all its edges, the last vertex is moved to the index of `vertex`
+    /// like `Vec::swap_remove`, return the old index of the moved vertex if there is one
+    pub fn remove_vertex(&mut self, vertex: usize) -> Option<usize> {
+        if vertex == 0 || vertex > self.num_vertices {
+            panic!("vertex with index {} does not exist!", vertex);
+        }
+        let incident_edges: Vec<usize> = self
+            .indexed_edge_iterator_of_vertex(vertex)
+            .chain(self.incoming_edge_iterator_of_vertex(vertex))
+            .map(|(edge_index, _)| edge_index)
+            .collect();
+        for edge_index in incident_edges {
+            // self loops appear in both chains
+            if self.contains_edge(edge_index) {
+                self.remove_edge(edge_index);
+            }
+        }
+
+        let last_vertex = self.num_vertices;
+        let moved = if vertex != last_vertex {
+            self.first[vertex] = self.first[last_vertex];
+            self.first_in[vertex] = self.first_in[last_vertex];
+            let mut edge_index = self.first[vertex];
+            while edge_index != -1 {
+                self.edges[edge_index as usize].as_mut().unwrap().in_vertex = vertex;
+                edge_index = self.next[edge_index as usize];
+            }
+            let mut edge_index = self.first_in[vertex];
+            while edge_index != -1 {
+                self.edges[edge_index as usize].as_mut().unwrap().out_vertex = vertex;
+                edge_index = self.next_in[edge_index as usize];
+            }
+            Some(last_vertex)
+        } else {
+            None
+        };
+
+        self.first.pop();
+        self.first_in.pop();
+        self.num_vertices -= 1;
+
+        moved
+    }
+
+    /// renumber the existing edges in ascending order of index so that there is no hole,
+    /// `mapping[old index]` is the new index or `None` if the edge has been removed
+    pub fn compact(&mut self) -> Vec<Option<usize>> {
+        let mut edges = std::mem::take(&mut self.edges);
+        edges.truncate(self._current_num_edges + 1);
+        let mut mapping = vec![None; edges.len()];
+        let num_existing_edges = self.num_existing_edges();
+        *self = AdjacencyList::new(self.num_vertices, num_existing_edges);
+        for (edge_index, edge) in edges.into_iter().enumerate() {
+            if let Some(edge) = edge {
+                mapping[edge_index] = Some(self.add_directed_edge(edge.in_vertex, edge.out_vertex, edge.weight));
+            }
+        }
+
+        mapping
     }
 
-    pub fn edge_iterator_of_vertex(&self, vertex: usize) -> EdgeIterator {
+    pub fn edge_iterator_of_vertex(&self, vertex: usize) -> EdgeIterator<'_, W> {
         EdgeIterator {
             graph: self,
             next_edge_index: self.first[vertex],
         }
     }
+
+    pub fn indexed_edge_iterator_of_vertex(&self, vertex: usize) -> IndexedEdgeIterator<'_, W> {
+        IndexedEdgeIterator {
+            edges: &self.edges,
+            next: &self.next,
+            next_edge_index: self.first[vertex],
+        }
+    }
+
+    /// edges whose out vertex is `vertex`
+    pub fn incoming_edge_iterator_of_vertex(&self, vertex: usize) -> IndexedEdgeIterator<'_, W> {
+        IndexedEdgeIterator {
+            edges: &self.edges,
+            next: &self.next_in,
+            next_edge_index: self.first_in[vertex],
+        }
+    }
+
+    /// get edge by its index, index of edge is started from 1
+    pub fn edge(&self, edge_index: usize) -> &Edge<W> {
+        match self.edges.get(edge_index) {
+            Some(Some(edge)) if edge_index > 0 => edge,
+            _ => panic!("edge with index {} does not exist!", edge_index),
+        }
+    }
 }
 
+pub type ClonedIndexedEdgeIterator<'a, W> = Map<IndexedEdgeIterator<'a, W>, fn((usize, &'a Edge<W>)) -> (usize, Edge<W>)>;
+
+impl<W: Weight> Adjacency<W> for AdjacencyList<W> {
+    type EdgeIter<'a> = Cloned<EdgeIterator<'a, W>> where W: 'a;
+    type IndexedEdgeIter<'a> = ClonedIndexedEdgeIterator<'a, W> where W: 'a;
+
+    fn num_vertices(&self) -> usize {
+        self.num_vertices()
+    }
+
+    fn num_edges(&self) -> usize {
+        self.num_edges()
+    }
 
-impl fmt::Display for AdjacencyList {
+    fn edge_iterator_of_vertex(&self, vertex: usize) -> Cloned<EdgeIterator<'_, W>> {
+        self.edge_iterator_of_vertex(vertex).cloned()
+    }
+
+    fn indexed_edge_iterator_of_vertex(&self, vertex: usize) -> ClonedIndexedEdgeIterator<'_, W> {
+        self.indexed_edge_iterator_of_vertex(vertex).map(|(x, edge)| (x, edge.clone()))
+    }
+
+    fn incoming_edge_iterator_of_vertex(&self, vertex: usize) -> ClonedIndexedEdgeIterator<'_, W> {
+        self.incoming_edge_iterator_of_vertex(vertex).map(|(x, edge)| (x, edge.clone()))
+    }
+
+    fn edge(&self, edge_index: usize) -> Edge<W> {
+        self.edge(edge_index).clone()
+    }
+}
+
+impl<W: Weight> fmt::Display for AdjacencyList<W> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result{
         let mut all_edge_strs = String::new();
-        for edge_index in 1..(self.edges.len()) {
-            all_edge_strs.push_str(self.edges[edge_index].as_ref().unwrap().to_str().as_str());
+        for edge in self.edges.iter().flatten() {
+            all_edge_strs.push_str(edge.to_str().as_str());
             all_edge_strs.push('\n');
         }
 
@@ -138,7 +412,8 @@ impl fmt::Display for AdjacencyList {
 }
 
 
-pub fn load_adj_list_from_file(file_name: &str) -> AdjacencyList{
+/// weights are parsed as `W`, so graphs with large costs can be loaded as `i64` or `u64`
+pub fn load_adj_list_from_file<W: Weight>(file_name: &str) -> AdjacencyList<W> {
     let content = fs::read_to_string(file_name)
         .expect(&format!("fail to read file: `{}`", file_name));
 
@@ -158,7 +433,10 @@ pub fn load_adj_list_from_file(file_name: &str) -> AdjacencyList{
         let line: Vec<&str> = line.split_ascii_whitespace().collect();
         let in_vertex = line[0].parse::<usize>().unwrap();
         let out_vertex = line[1].parse::<usize>().unwrap();
-        let weight = line[2].parse::<i32>().unwrap();
+        let weight = match line[2].parse::<W>() {
+            Ok(x) => x,
+            Err(_) => panic!("fail to parse weight: `{}`!", line[2]),
+        };
 
         graph.add_directed_edge(in_vertex, out_vertex, weight);
     }
@@ -175,7 +453,7 @@ mod tests {
 
     #[test]
     fn test_load_graph_from_file() {
-        let adj_list = load_adj_list_from_file(TEST_GRAPH_FILE);
+        let adj_list: AdjacencyList = load_adj_list_from_file(TEST_GRAPH_FILE);
         assert_eq!(adj_list.num_edges(), 5);
         assert_eq!(adj_list.num_vertices(), 4);
     }
@@ -192,4 +470,89 @@ mod tests {
         assert!(edge.is_none());
     }
 
+    #[test]
+    fn test_indexed_edge_iterator() {
+        let adj_list: AdjacencyList = load_adj_list_from_file(TEST_GRAPH_FILE);
+        let edge_indices: Vec<usize> = adj_list
+            .indexed_edge_iterator_of_vertex(1)
+            .map(|(edge_index, _)| edge_index)
+            .collect();
+        assert_eq!(edge_indices, vec![3, 1]);
+        assert_eq!(adj_list.edge(3).out_vertex(), 4);
+    }
+
+    #[test]
+    fn test_incoming_edge_iterator() {
+        let adj_list: AdjacencyList = load_adj_list_from_file(TEST_GRAPH_FILE);
+        let in_vertices: Vec<(usize, usize)> = adj_list
+            .incoming_edge_iterator_of_vertex(4)
+            .map(|(edge_index, edge)| (edge_index, edge.in_vertex))
+            .collect();
+        assert_eq!(in_vertices, vec![(5, 3), (4, 2), (3, 1)]);
+        assert!(adj_list.incoming_edge_iterator_of_vertex(1).next().is_none());
+    }
+
+    #[test]
+    fn test_grow_adj_list() {
+        let mut adj_list: AdjacencyList = AdjacencyList::new(1, 0);
+        let vertex = adj_list.add_vertex();
+        assert_eq!(vertex, 2);
+        for weight in 1..=5 {
+            adj_list.add_directed_edge(1, vertex, weight);
+        }
+        assert_eq!(adj_list.num_existing_edges(), 5);
+        assert!(adj_list.num_edges() >= 5);
+        assert_eq!(adj_list.edge(5).weight(), 5);
+        assert_eq!(adj_list.incoming_edge_iterator_of_vertex(vertex).count(), 5);
+    }
+
+    #[test]
+    fn test_remove_edge() {
+        let mut adj_list: AdjacencyList = load_adj_list_from_file(TEST_GRAPH_FILE);
+        let edge = adj_list.remove_edge(3);
+        assert_eq!((edge.in_vertex, edge.out_vertex), (1, 4));
+        assert!(!adj_list.contains_edge(3));
+        assert_eq!(adj_list.num_existing_edges(), 4);
+
+        let edge_indices: Vec<usize> = adj_list
+            .indexed_edge_iterator_of_vertex(1)
+            .map(|(edge_index, _)| edge_index)
+            .collect();
+        assert_eq!(edge_indices, vec![1]);
+        let edge_indices: Vec<usize> = adj_list
+            .incoming_edge_iterator_of_vertex(4)
+            .map(|(edge_index, _)| edge_index)
+            .collect();
+        assert_eq!(edge_indices, vec![5, 4]);
+        assert_eq!(adj_list.to_string().lines().count(), 4);
+
+        // a new edge takes a new index, the hole is kept until compaction
+        assert_eq!(adj_list.add_directed_edge(4, 1, 1), 6);
+        let mapping = adj_list.compact();
+        assert_eq!(mapping, vec![None, Some(1), Some(2), None, Some(3), Some(4), Some(5)]);
+        assert_eq!(adj_list.num_edges(), 5);
+        assert_eq!(adj_list.edge(5).in_vertex(), 4);
+        assert_eq!(adj_list.incoming_edge_iterator_of_vertex(4).count(), 2);
+    }
+
+    #[test]
+    fn test_remove_vertex() {
+        let mut adj_list: AdjacencyList = load_adj_list_from_file(TEST_GRAPH_FILE);
+        adj_list.add_directed_edge(4, 4, 1);
+        // vertex 4 is moved to index 2
+        assert_eq!(adj_list.remove_vertex(2), Some(4));
+        assert_eq!(adj_list.num_vertices(), 3);
+        assert_eq!(adj_list.num_existing_edges(), 3);
+
+        let mut edges: Vec<(usize, usize, i32)> = (1..=3)
+            .flat_map(|x| adj_list.edge_iterator_of_vertex(x))
+            .map(|edge| (edge.in_vertex, edge.out_vertex, edge.weight))
+            .collect();
+        edges.sort();
+        assert_eq!(edges, vec![(1, 2, 8), (2, 2, 1), (3, 2, -2)]);
+
+        assert_eq!(adj_list.remove_vertex(3), None);
+        assert_eq!(adj_list.incoming_edge_iterator_of_vertex(2).count(), 2);
+    }
+
 }
\ No newline at end of file
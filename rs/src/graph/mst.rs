@@ -0,0 +1,166 @@
+use super::algorithms::Graph;
+use super::{Adjacency, Weight};
+
+use crate::heap::{BinaryMinHeap, HeapElement, HeapMethod};
+use crate::union_find::{DisjointSet, UnionFindMethod};
+
+/// [minimum spanning forest](https://en.wikipedia.org/wiki/Minimum_spanning_tree),
+/// one tree for each connected component
+#[derive(Debug)]
+pub struct SpanningForest<W = i32> {
+    /// index of one of the two directed edges of each chosen undirected edge
+    edges: Vec<usize>,
+    weight: W,
+}
+
+impl<W: Weight> SpanningForest<W> {
+    pub fn edges(&self) -> &[usize] {
+        &self.edges
+    }
+
+    pub fn weight(&self) -> W {
+        self.weight
+    }
+}
+
+/// minimum spanning forest of undirected graphs built with `AdjacencyList::add_undirected_edge`
+impl<W: Weight, A: Adjacency<W>> Graph<W, A> {
+    /// [Kruskal](https://en.wikipedia.org/wiki/Kruskal%27s_algorithm),
+    /// add edges in ascending order of weight unless they form a cycle
+    pub fn kruskal(&self) -> SpanningForest<W> {
+        let adj_list = self.adj_list();
+        let num_vertices = adj_list.num_vertices();
+
+        let mut edges: Vec<(usize, W)> = (1..=num_vertices)
+            .flat_map(|vertex| adj_list.indexed_edge_iterator_of_vertex(vertex))
+            .map(|(edge_index, edge)| (edge_index, edge.weight))
+            .collect();
+        edges.sort_by_key(|&(_, weight)| weight);
+
+        let mut disjoint_set = DisjointSet::one_based(num_vertices);
+        let mut forest = SpanningForest {
+            edges: Vec::with_capacity(num_vertices),
+            weight: W::zero(),
+        };
+
+        for (edge_index, weight) in edges {
+            let edge = adj_list.edge(edge_index);
+            if disjoint_set.union(edge.in_vertex, edge.out_vertex) {
+                forest.edges.push(edge_index);
+                forest.weight = forest.weight.add_or_panic(weight);
+            }
+        }
+
+        forest
+    }
+
+    /// [Prim](https://en.wikipedia.org/wiki/Prim%27s_algorithm),
+    /// grow a tree from every vertex not spanned yet by the cheapest edge leaving it
+    pub fn prim(&self) -> SpanningForest<W> {
+        let adj_list = self.adj_list();
+        let num_vertices = adj_list.num_vertices();
+
+        let mut in_tree = vec![false; num_vertices + 1];
+        // cheapest edge connecting each vertex to the tree and its weight
+        let mut cheapest_edge: Vec<Option<(usize, W)>> = vec![None; num_vertices + 1];
+        // key is the weight of cheapest edge and track_id is the vertex
+        let mut heap: BinaryMinHeap<W, usize> = BinaryMinHeap::with_capacity(num_vertices);
+
+        let mut forest = SpanningForest {
+            edges: Vec::with_capacity(num_vertices),
+            weight: W::zero(),
+        };
+
+        for root in 1..=num_vertices {
+            if in_tree[root] {
+                continue;
+            }
+
+            heap.push(HeapElement::new(W::zero(), root));
+            while let Some(element) = heap.pop() {
+                let vertex = element.track_id();
+                in_tree[vertex] = true;
+                if let Some((edge_index, weight)) = cheapest_edge[vertex] {
+                    forest.edges.push(edge_index);
+                    forest.weight = forest.weight.add_or_panic(weight);
+                }
+
+                for (edge_index, edge) in adj_list.indexed_edge_iterator_of_vertex(vertex) {
+                    if in_tree[edge.out_vertex] {
+                        continue;
+                    }
+                    match cheapest_edge[edge.out_vertex] {
+                        Some((_, weight)) if weight <= edge.weight => {},
+                        _ => {
+                            cheapest_edge[edge.out_vertex] = Some((edge_index, edge.weight));
+                            heap.push(HeapElement::new(edge.weight, edge.out_vertex));
+                        }
+                    }
+                }
+            }
+        }
+
+        forest
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{load_adj_list_from_file, AdjacencyList};
+
+    const POSITIVE_TEST_GRAPH_FILE: &str = "src/graph/examples/positive_test.txt";
+    const POSITIVE_SIMPLE_GRAPH_FILE: &str = "src/graph/examples/positive_simple.txt";
+
+    fn assert_spanning_forest(graph: &Graph, forest: &SpanningForest, num_trees: usize) {
+        let num_vertices = graph.adj_list().num_vertices();
+        assert_eq!(forest.edges().len(), num_vertices - num_trees);
+
+        let weight: i32 = forest.edges().iter().map(|&x| graph.adj_list().edge(x).weight()).sum();
+        assert_eq!(weight, forest.weight());
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree() {
+        let graph = Graph::create_from_file(POSITIVE_TEST_GRAPH_FILE);
+        for forest in [graph.kruskal(), graph.prim()].iter() {
+            assert_spanning_forest(&graph, forest, 1);
+            assert_eq!(forest.weight(), 36);
+        }
+    }
+
+    #[test]
+    fn test_minimum_spanning_forest() {
+        let mut adj_list = AdjacencyList::new(6, 10);
+        adj_list.add_undirected_edge(1, 2, 3);
+        adj_list.add_undirected_edge(2, 3, 1);
+        adj_list.add_undirected_edge(1, 3, 2);
+        adj_list.add_undirected_edge(4, 5, -1);
+        adj_list.add_undirected_edge(4, 5, 5);
+        let graph = Graph::new(adj_list);
+
+        for forest in [graph.kruskal(), graph.prim()].iter() {
+            assert_spanning_forest(&graph, forest, 3);
+            assert_eq!(forest.weight(), 2);
+        }
+    }
+
+    #[test]
+    fn test_kruskal_same_as_prim() {
+        // make the random directed graph undirected
+        let directed: AdjacencyList = load_adj_list_from_file(POSITIVE_SIMPLE_GRAPH_FILE);
+        let mut adj_list = AdjacencyList::new(directed.num_vertices(), 2 * directed.num_edges());
+        for vertex in 1..=directed.num_vertices() {
+            for edge in directed.edge_iterator_of_vertex(vertex) {
+                adj_list.add_undirected_edge(edge.in_vertex(), edge.out_vertex(), edge.weight());
+            }
+        }
+        let graph = Graph::new(adj_list);
+
+        let kruskal = graph.kruskal();
+        let prim = graph.prim();
+        assert_eq!(kruskal.weight(), prim.weight());
+        assert_eq!(kruskal.edges().len(), prim.edges().len());
+    }
+}
@@ -0,0 +1,259 @@
+use super::algorithms::Graph;
+use super::shortest_path::{Path, ShortestPaths};
+use super::{Adjacency, Weight};
+
+use crate::heap::{BinaryMinHeap, HeapElement, HeapMethod};
+
+/// estimate of the distance from a vertex to the goal used by a*,
+/// it must never overestimate the real distance, otherwise the path found may not be the shortest
+pub trait Heuristic<W = i32> {
+    fn estimate(&self, vertex: usize) -> W;
+}
+
+impl<W, F: Fn(usize) -> W> Heuristic<W> for F {
+    fn estimate(&self, vertex: usize) -> W {
+        self(vertex)
+    }
+}
+
+
+/// shortest path between two vertices, weight of all edges must be non-negative
+impl<W: Weight, A: Adjacency<W>> Graph<W, A> {
+    /// [A*](https://en.wikipedia.org/wiki/A*_search_algorithm),
+    /// stop as soon as `target` is settled, `None` if `target` can not be reached
+    pub fn a_star(&self, source: usize, target: usize, heuristic: &dyn Heuristic<W>) -> Option<Path<W>> {
+        let adj_list = self.adj_list();
+        let num_vertices = adj_list.num_vertices();
+
+        // distance from source, not the estimated total distance
+        let mut shortest_paths = ShortestPaths::new(source, num_vertices);
+        // key is distance from source plus the estimate, track_id is the vertex
+        let mut heap: BinaryMinHeap<W, usize> = BinaryMinHeap::with_capacity(num_vertices);
+
+        heap.push(HeapElement::new(heuristic.estimate(source), source));
+
+        while let Some(element) = heap.pop() {
+            let vertex = element.track_id();
+            if vertex == target {
+                return shortest_paths.path_to(target);
+            }
+            let distance = shortest_paths.distance(vertex).unwrap();
+
+            for (edge_index, edge) in adj_list.indexed_edge_iterator_of_vertex(vertex) {
+                let new_distance = distance.add_or_panic(edge.weight);
+                match shortest_paths.distance(edge.out_vertex) {
+                    Some(x) if x <= new_distance => {},
+                    _ => {
+                        shortest_paths.update(edge.out_vertex, new_distance, vertex, edge_index);
+                        // push decreases key if vertex is still in heap,
+                        // and reopens it if it has been settled with an inconsistent heuristic
+                        let estimate = new_distance.add_or_panic(heuristic.estimate(edge.out_vertex));
+                        heap.push(HeapElement::new(estimate, edge.out_vertex));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// [bidirectional dijkstra](https://en.wikipedia.org/wiki/Bidirectional_search),
+    /// search forward from `source` along outgoing edges and backward from `target` along incoming edges,
+    /// `None` if `target` can not be reached
+    pub fn bidirectional_dijkstra(&self, source: usize, target: usize) -> Option<Path<W>> {
+        let num_vertices = self.adj_list().num_vertices();
+
+        let mut forward: DijkstraSearch<W> = DijkstraSearch::new(source, num_vertices);
+        let mut backward = DijkstraSearch::new(target, num_vertices);
+        // shortest distance found so far and the vertex where two searches meet
+        let mut best = match source == target {
+            true => Some((W::zero(), source)),
+            false => None,
+        };
+
+        // stop if one side has settled everything it can reach
+        while let (Some(forward_key), Some(backward_key)) = (forward.top_key(), backward.top_key()) {
+            // any path not found yet is at least as long as the sum of both tops
+            if let Some((distance, _)) = best {
+                if forward_key.saturating_add(backward_key) >= distance {
+                    break;
+                }
+            }
+
+            if forward_key <= backward_key {
+                self.bidirectional_step(&mut forward, &backward, false, &mut best);
+            } else {
+                self.bidirectional_step(&mut backward, &forward, true, &mut best);
+            }
+        }
+
+        let (cost, meeting_vertex) = best?;
+
+        let forward_path = forward.shortest_paths.path_to(meeting_vertex).unwrap();
+        let mut vertices = forward_path.vertices().to_vec();
+        let mut edges = forward_path.edges().to_vec();
+        let mut current_vertex = meeting_vertex;
+        while current_vertex != target {
+            edges.push(backward.shortest_paths.predecessor(current_vertex).unwrap());
+            current_vertex = backward.shortest_paths.predecessor_vertex(current_vertex).unwrap();
+            vertices.push(current_vertex);
+        }
+
+        Some(Path::new(vertices, edges, cost))
+    }
+
+    /// settle the top vertex of `search`, edges are followed backward if `reverse`
+    fn bidirectional_step(
+        &self,
+        search: &mut DijkstraSearch<W>,
+        other: &DijkstraSearch<W>,
+        reverse: bool,
+        best: &mut Option<(W, usize)>,
+    ) {
+        let adj_list = self.adj_list();
+
+        let element = search.heap.pop().unwrap();
+        let vertex = element.track_id();
+        let distance = *element.key();
+        search.settled[vertex] = true;
+
+        let edge_iterator = match reverse {
+            false => adj_list.indexed_edge_iterator_of_vertex(vertex),
+            true => adj_list.incoming_edge_iterator_of_vertex(vertex),
+        };
+        for (edge_index, edge) in edge_iterator {
+            let next_vertex = match reverse {
+                false => edge.out_vertex,
+                true => edge.in_vertex,
+            };
+            if search.settled[next_vertex] {continue;}
+
+            let new_distance = distance.add_or_panic(edge.weight);
+            match search.shortest_paths.distance(next_vertex) {
+                Some(x) if x <= new_distance => continue,
+                _ => {
+                    search.shortest_paths.update(next_vertex, new_distance, vertex, edge_index);
+                    search.heap.push(HeapElement::new(new_distance, next_vertex));
+                }
+            }
+
+            if let Some(other_distance) = other.shortest_paths.distance(next_vertex) {
+                let total_distance = new_distance.add_or_panic(other_distance);
+                match *best {
+                    Some((x, _)) if x <= total_distance => {},
+                    _ => *best = Some((total_distance, next_vertex)),
+                }
+            }
+        }
+    }
+}
+
+
+/// state of one direction in bidirectional dijkstra
+struct DijkstraSearch<W: Weight> {
+    shortest_paths: ShortestPaths<W>,
+    heap: BinaryMinHeap<W, usize>,
+    settled: Vec<bool>,
+}
+
+impl<W: Weight> DijkstraSearch<W> {
+    fn new(source: usize, num_vertices: usize) -> DijkstraSearch<W> {
+        let mut heap = BinaryMinHeap::with_capacity(num_vertices);
+        heap.push(HeapElement::new(W::zero(), source));
+
+        DijkstraSearch {
+            shortest_paths: ShortestPaths::new(source, num_vertices),
+            heap,
+            settled: vec![false; num_vertices + 1],
+        }
+    }
+
+    fn top_key(&self) -> Option<W> {
+        self.heap.peek().map(|x| *x.key())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::AdjacencyList;
+
+    const POSITIVE_TEST_GRAPH_FILE: &str = "src/graph/examples/positive_test.txt";
+    const POSITIVE_SIMPLE_GRAPH_FILE: &str = "src/graph/examples/positive_simple.txt";
+
+    #[test]
+    fn test_a_star() {
+        let graph = Graph::create_from_file(POSITIVE_TEST_GRAPH_FILE);
+
+        let path = graph.a_star(4, 1, &|_| 0).unwrap();
+        assert_eq!(path.vertices(), &[4, 5, 6, 1]);
+        assert_eq!(path.cost(), 22);
+
+        // graph is undirected, so distances to 1 are distances from 1
+        let to_target = graph.dijkstra(1);
+        let heuristic = |vertex| to_target.distance(vertex).unwrap();
+        assert_eq!(graph.a_star(4, 1, &heuristic).unwrap(), path);
+    }
+
+    #[test]
+    fn test_a_star_same_as_dijkstra() {
+        let graph = Graph::create_from_file(POSITIVE_SIMPLE_GRAPH_FILE);
+        let shortest_paths = graph.dijkstra(1);
+
+        for target in (1..=graph.adj_list().num_vertices()).step_by(97) {
+            let path = graph.a_star(1, target, &|_| 0);
+            assert_eq!(path.map(|x| x.cost()), shortest_paths.distance(target));
+        }
+    }
+
+    #[test]
+    fn test_a_star_unreachable() {
+        let mut adj_list = AdjacencyList::new(3, 1);
+        adj_list.add_directed_edge(1, 2, 5);
+        let graph = Graph::new(adj_list);
+
+        assert!(graph.a_star(1, 3, &|_| 0).is_none());
+        assert!(graph.a_star(2, 1, &|_| 0).is_none());
+    }
+
+    #[test]
+    fn test_bidirectional_dijkstra() {
+        let graph = Graph::create_from_file(POSITIVE_TEST_GRAPH_FILE);
+
+        let path = graph.bidirectional_dijkstra(4, 1).unwrap();
+        assert_eq!(path.vertices(), &[4, 5, 6, 1]);
+        assert_eq!(path.cost(), 22);
+        for (i, &edge_index) in path.edges().iter().enumerate() {
+            let edge = graph.adj_list().edge(edge_index);
+            assert_eq!((edge.in_vertex(), edge.out_vertex()), (path.vertices()[i], path.vertices()[i + 1]));
+        }
+
+        let path = graph.bidirectional_dijkstra(3, 3).unwrap();
+        assert_eq!((path.vertices(), path.cost()), (&[3][..], 0));
+    }
+
+    #[test]
+    fn test_bidirectional_dijkstra_same_as_dijkstra() {
+        let graph: Graph = Graph::create_from_file(POSITIVE_SIMPLE_GRAPH_FILE);
+
+        for source in (1..=graph.adj_list().num_vertices()).step_by(331) {
+            let shortest_paths = graph.dijkstra(source);
+            for target in (1..=graph.adj_list().num_vertices()).step_by(97) {
+                let path = graph.bidirectional_dijkstra(source, target);
+                assert_eq!(path.map(|x| x.cost()), shortest_paths.distance(target));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bidirectional_dijkstra_unreachable() {
+        let mut adj_list = AdjacencyList::new(3, 1);
+        adj_list.add_directed_edge(1, 2, 5);
+        let graph = Graph::new(adj_list);
+
+        assert!(graph.bidirectional_dijkstra(1, 3).is_none());
+        assert!(graph.bidirectional_dijkstra(2, 1).is_none());
+        assert_eq!(graph.bidirectional_dijkstra(1, 2).unwrap().cost(), 5);
+    }
+}
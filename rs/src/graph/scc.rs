@@ -0,0 +1,276 @@
+use super::algorithms::Graph;
+use super::traversal::DfsOrder;
+use super::{Adjacency, AdjacencyList, Weight};
+
+use std::collections::HashMap;
+
+/// [strongly connected components](https://en.wikipedia.org/wiki/Strongly_connected_component)
+/// components are numbered from 1 in topological order of the condensation,
+/// so every edge between two components goes from a smaller id to a larger one
+#[derive(Debug)]
+pub struct StronglyConnectedComponents {
+    /// component id of each vertex, index 0 is never used
+    component: Vec<usize>,
+    num_components: usize,
+}
+
+impl StronglyConnectedComponents {
+    pub fn num_components(&self) -> usize {
+        self.num_components
+    }
+
+    pub fn component(&self, vertex: usize) -> usize {
+        self.component[vertex]
+    }
+
+    /// vertices of each component in ascending order, index 0 is always empty
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut components = vec![Vec::new(); self.num_components + 1];
+        for vertex in 1..self.component.len() {
+            components[self.component[vertex]].push(vertex);
+        }
+        components
+    }
+}
+
+/// which edges between components are kept in condensation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CondensationEdges {
+    /// every edge whose vertices are in different components, parallel edges are kept
+    All,
+    /// only the edge with minimum weight between each pair of components
+    MinWeight,
+}
+
+
+/// strongly connected components of directed graphs
+impl<W: Weight, A: Adjacency<W>> Graph<W, A> {
+    /// [Tarjan](https://en.wikipedia.org/wiki/Tarjan%27s_strongly_connected_components_algorithm),
+    /// one dfs with low link values
+    pub fn tarjan_scc(&self) -> StronglyConnectedComponents {
+        let adj_list = self.adj_list();
+        let num_vertices = adj_list.num_vertices();
+
+        // order in which vertices are discovered, 0 means not discovered yet
+        let mut index = vec![0; num_vertices + 1];
+        let mut low_link = vec![0; num_vertices + 1];
+        let mut on_stack = vec![false; num_vertices + 1];
+        let mut stack = Vec::with_capacity(num_vertices);
+        let mut current_index = 0;
+
+        let mut component = vec![0; num_vertices + 1];
+        let mut num_components = 0;
+
+        for root in 1..=num_vertices {
+            if index[root] != 0 {
+                continue;
+            }
+
+            current_index += 1;
+            index[root] = current_index;
+            low_link[root] = current_index;
+            stack.push(root);
+            on_stack[root] = true;
+            // each vertex on call stack keeps the iterator over its remaining edges
+            let mut call_stack = vec![(root, adj_list.indexed_edge_iterator_of_vertex(root))];
+
+            while let Some((vertex, edge_iterator)) = call_stack.last_mut() {
+                let vertex = *vertex;
+                match edge_iterator.next() {
+                    Some((_, edge)) => {
+                        let next_vertex = edge.out_vertex;
+                        if index[next_vertex] == 0 {
+                            current_index += 1;
+                            index[next_vertex] = current_index;
+                            low_link[next_vertex] = current_index;
+                            stack.push(next_vertex);
+                            on_stack[next_vertex] = true;
+                            call_stack.push((next_vertex, adj_list.indexed_edge_iterator_of_vertex(next_vertex)));
+                        } else if on_stack[next_vertex] {
+                            low_link[vertex] = low_link[vertex].min(index[next_vertex]);
+                        }
+                    },
+                    None => {
+                        call_stack.pop();
+                        if let Some((parent, _)) = call_stack.last() {
+                            low_link[*parent] = low_link[*parent].min(low_link[vertex]);
+                        }
+
+                        // vertex is the root of a component
+                        if low_link[vertex] == index[vertex] {
+                            num_components += 1;
+                            loop {
+                                let member = stack.pop().unwrap();
+                                on_stack[member] = false;
+                                component[member] = num_components;
+                                if member == vertex {
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // components are found in reverse topological order
+        for id in component.iter_mut().skip(1) {
+            *id = num_components + 1 - *id;
+        }
+
+        StronglyConnectedComponents {
+            component,
+            num_components,
+        }
+    }
+
+    /// [Kosaraju](https://en.wikipedia.org/wiki/Kosaraju%27s_algorithm),
+    /// dfs on graph for finishing order then dfs on reversed graph along incoming edges
+    pub fn kosaraju_scc(&self) -> StronglyConnectedComponents {
+        let adj_list = self.adj_list();
+        let num_vertices = adj_list.num_vertices();
+
+        let mut component = vec![0; num_vertices + 1];
+        let mut num_components = 0;
+        let mut stack = Vec::with_capacity(num_vertices);
+
+        for visit in self.dfs_iter_from(1..=num_vertices, DfsOrder::ReversePostorder) {
+            let root = visit.vertex();
+            if component[root] != 0 {
+                continue;
+            }
+
+            num_components += 1;
+            component[root] = num_components;
+            stack.push(root);
+            while let Some(vertex) = stack.pop() {
+                for (_, edge) in adj_list.incoming_edge_iterator_of_vertex(vertex) {
+                    if component[edge.in_vertex] == 0 {
+                        component[edge.in_vertex] = num_components;
+                        stack.push(edge.in_vertex);
+                    }
+                }
+            }
+        }
+
+        StronglyConnectedComponents {
+            component,
+            num_components,
+        }
+    }
+
+    /// contract each component into a single vertex whose index is the component id,
+    /// the condensation is always acyclic
+    pub fn condensation(&self, scc: &StronglyConnectedComponents, edges: CondensationEdges) -> Graph<W> {
+        let adj_list = self.adj_list();
+
+        // (in component, out component, weight) of edges between components
+        let mut cross_edges = Vec::new();
+        for vertex in 1..=adj_list.num_vertices() {
+            for edge in adj_list.edge_iterator_of_vertex(vertex) {
+                let in_component = scc.component(edge.in_vertex);
+                let out_component = scc.component(edge.out_vertex);
+                if in_component != out_component {
+                    cross_edges.push((in_component, out_component, edge.weight));
+                }
+            }
+        }
+
+        if edges == CondensationEdges::MinWeight {
+            let mut min_weight: HashMap<(usize, usize), W> = HashMap::with_capacity(cross_edges.len());
+            for &(in_component, out_component, weight) in cross_edges.iter() {
+                let current_weight = min_weight.entry((in_component, out_component)).or_insert(weight);
+                *current_weight = (*current_weight).min(weight);
+            }
+            cross_edges = min_weight
+                .into_iter()
+                .map(|((in_component, out_component), weight)| (in_component, out_component, weight))
+                .collect();
+            cross_edges.sort_unstable();
+        }
+
+        let mut condensation = AdjacencyList::new(scc.num_components(), cross_edges.len());
+        for (in_component, out_component, weight) in cross_edges {
+            condensation.add_directed_edge(in_component, out_component, weight);
+        }
+
+        Graph::new(condensation)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIMPLE_GRAPH_FILE: &str = "src/graph/examples/simple.txt";
+
+    fn three_components_graph() -> Graph {
+        let mut adj_list = AdjacencyList::new(7, 10);
+        adj_list.add_directed_edge(1, 2, 1);
+        adj_list.add_directed_edge(2, 3, 1);
+        adj_list.add_directed_edge(3, 1, 1);
+        adj_list.add_directed_edge(3, 4, 5);
+        adj_list.add_directed_edge(2, 5, 3);
+        adj_list.add_directed_edge(4, 5, 1);
+        adj_list.add_directed_edge(5, 6, 1);
+        adj_list.add_directed_edge(6, 4, 1);
+        adj_list.add_directed_edge(6, 7, 2);
+        adj_list.add_directed_edge(7, 7, 1);
+        Graph::new(adj_list)
+    }
+
+    /// relabel components by order of first appearance to compare partitions
+    fn normalize(scc: &StronglyConnectedComponents) -> Vec<usize> {
+        let mut relabel = vec![0; scc.num_components() + 1];
+        let mut num_labels = 0;
+        let mut labels = Vec::new();
+        for vertex in 1..scc.component.len() {
+            let id = scc.component(vertex);
+            if relabel[id] == 0 {
+                num_labels += 1;
+                relabel[id] = num_labels;
+            }
+            labels.push(relabel[id]);
+        }
+        labels
+    }
+
+    #[test]
+    fn test_scc() {
+        let graph = three_components_graph();
+        for scc in [graph.tarjan_scc(), graph.kosaraju_scc()].iter() {
+            assert_eq!(scc.num_components(), 3);
+            assert_eq!(scc.component[1..], [1, 1, 1, 2, 2, 2, 3]);
+            assert_eq!(scc.components()[1..], [vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+        }
+    }
+
+    #[test]
+    fn test_tarjan_same_as_kosaraju() {
+        let graph: Graph = Graph::create_from_file(SIMPLE_GRAPH_FILE);
+        let tarjan = graph.tarjan_scc();
+        let kosaraju = graph.kosaraju_scc();
+
+        assert_eq!(tarjan.num_components(), kosaraju.num_components());
+        assert_eq!(normalize(&tarjan), normalize(&kosaraju));
+    }
+
+    #[test]
+    fn test_condensation() {
+        let graph = three_components_graph();
+        let scc = graph.tarjan_scc();
+
+        let condensation = graph.condensation(&scc, CondensationEdges::All);
+        assert_eq!(condensation.adj_list().num_vertices(), 3);
+        assert_eq!(condensation.adj_list().num_edges(), 3);
+        assert!(condensation.is_acyclic());
+
+        let condensation = graph.condensation(&scc, CondensationEdges::MinWeight);
+        let edges: Vec<(usize, usize, i32)> = (1..=3)
+            .flat_map(|vertex| condensation.adj_list().edge_iterator_of_vertex(vertex))
+            .map(|edge| (edge.in_vertex(), edge.out_vertex(), edge.weight()))
+            .collect();
+        assert_eq!(edges, vec![(1, 2, 3), (2, 3, 2)]);
+    }
+}
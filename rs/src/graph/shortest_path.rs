@@ -0,0 +1,417 @@
+use super::algorithms::Graph;
+use super::{Adjacency, Weight};
+
+use std::collections::VecDeque;
+use std::fmt;
+
+/// a path found by shortest path algorithms
+/// `vertices` begins with the source and ends with the target,
+/// `edges[i]` is the index of the edge from `vertices[i]` to `vertices[i+1]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path<W = i32> {
+    vertices: Vec<usize>,
+    edges: Vec<usize>,
+    cost: W,
+}
+
+impl<W: Weight> Path<W> {
+    pub(super) fn new(vertices: Vec<usize>, edges: Vec<usize>, cost: W) -> Path<W> {
+        Path {
+            vertices,
+            edges,
+            cost,
+        }
+    }
+
+    pub fn vertices(&self) -> &[usize] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> &[usize] {
+        &self.edges
+    }
+
+    pub fn cost(&self) -> W {
+        self.cost
+    }
+}
+
+/// result of single source shortest path algorithms
+/// index of vertex is started from 1, so index 0 is never used
+#[derive(Debug)]
+pub struct ShortestPaths<W = i32> {
+    source: usize,
+    /// `None` means the vertex can not be reached from source
+    distance: Vec<Option<W>>,
+    /// index of the last edge on the shortest path to each vertex
+    predecessor_edge: Vec<Option<usize>>,
+    /// the vertex that last edge comes from
+    predecessor_vertex: Vec<Option<usize>>,
+}
+
+impl<W: Weight> ShortestPaths<W> {
+    pub(super) fn new(source: usize, num_vertices: usize) -> ShortestPaths<W> {
+        let mut distance = vec![None; num_vertices + 1];
+        distance[source] = Some(W::zero());
+
+        ShortestPaths {
+            source,
+            distance,
+            predecessor_edge: vec![None; num_vertices + 1],
+            predecessor_vertex: vec![None; num_vertices + 1],
+        }
+    }
+
+    /// every vertex is a source with distance 0, same as adding a virtual source
+    /// with zero weight edges to all vertices, `path_to` is not available
+    fn with_all_sources(num_vertices: usize) -> ShortestPaths<W> {
+        ShortestPaths {
+            source: 0,
+            distance: vec![Some(W::zero()); num_vertices + 1],
+            predecessor_edge: vec![None; num_vertices + 1],
+            predecessor_vertex: vec![None; num_vertices + 1],
+        }
+    }
+
+    /// record that `vertex` can be reached with `distance` through edge `edge_index` from `from_vertex`
+    pub(super) fn update(&mut self, vertex: usize, distance: W, from_vertex: usize, edge_index: usize) {
+        self.distance[vertex] = Some(distance);
+        self.predecessor_edge[vertex] = Some(edge_index);
+        self.predecessor_vertex[vertex] = Some(from_vertex);
+    }
+
+    pub fn source(&self) -> usize {
+        self.source
+    }
+
+    pub fn num_vertices(&self) -> usize {
+        self.distance.len() - 1
+    }
+
+    pub fn distance(&self, vertex: usize) -> Option<W> {
+        self.distance[vertex]
+    }
+
+    pub fn distances(&self) -> &[Option<W>] {
+        &self.distance
+    }
+
+    pub fn is_reachable(&self, vertex: usize) -> bool {
+        self.distance[vertex].is_some()
+    }
+
+    /// index of the edge through which `vertex` is reached,
+    /// `None` for the source and unreachable vertices
+    pub fn predecessor(&self, vertex: usize) -> Option<usize> {
+        self.predecessor_edge[vertex]
+    }
+
+    pub fn predecessor_vertex(&self, vertex: usize) -> Option<usize> {
+        self.predecessor_vertex[vertex]
+    }
+
+    /// walk back along predecessors from `vertex` to find a cycle,
+    /// `vertex` must have been relaxed after the shortest paths should have converged
+    pub(super) fn find_cycle(&self, vertex: usize) -> NegativeCycle {
+        // after walking back `num_vertices` times we must be on the cycle
+        let mut start_vertex = vertex;
+        for _ in 0..self.num_vertices() {
+            start_vertex = self.predecessor_vertex[start_vertex].unwrap();
+        }
+
+        self.collect_cycle(start_vertex)
+    }
+
+    /// search the whole predecessor graph for a cycle
+    pub(super) fn find_any_cycle(&self) -> Option<NegativeCycle> {
+        // the vertex from which the walk visiting each vertex started, 0 means not visited
+        let mut walk_start = vec![0; self.num_vertices() + 1];
+
+        for vertex in 1..=self.num_vertices() {
+            let mut current_vertex = vertex;
+            while walk_start[current_vertex] == 0 {
+                walk_start[current_vertex] = vertex;
+                match self.predecessor_vertex[current_vertex] {
+                    Some(x) => current_vertex = x,
+                    None => break,
+                }
+            }
+            // come back to a vertex visited in this walk
+            if walk_start[current_vertex] == vertex && self.predecessor_vertex[current_vertex].is_some() {
+                return Some(self.collect_cycle(current_vertex));
+            }
+        }
+
+        None
+    }
+
+    fn collect_cycle(&self, start_vertex: usize) -> NegativeCycle {
+        let mut vertices = Vec::new();
+        let mut edges = Vec::new();
+        let mut current_vertex = start_vertex;
+        loop {
+            vertices.push(current_vertex);
+            edges.push(self.predecessor_edge[current_vertex].unwrap());
+            current_vertex = self.predecessor_vertex[current_vertex].unwrap();
+            if current_vertex == start_vertex {
+                break;
+            }
+        }
+        // we walked backwards, `edges[i]` goes from `vertices[i+1]` to `vertices[i]`
+        vertices.reverse();
+        edges.reverse();
+        vertices.rotate_right(1);
+
+        NegativeCycle { vertices, edges }
+    }
+
+    /// rebuild the shortest path from source to `target`
+    pub fn path_to(&self, target: usize) -> Option<Path<W>> {
+        let cost = self.distance[target]?;
+
+        let mut vertices = vec![target];
+        let mut edges = Vec::new();
+        let mut current_vertex = target;
+        while current_vertex != self.source {
+            edges.push(self.predecessor_edge[current_vertex].unwrap());
+            current_vertex = self.predecessor_vertex[current_vertex].unwrap();
+            vertices.push(current_vertex);
+        }
+        vertices.reverse();
+        edges.reverse();
+
+        Some(Path::new(vertices, edges, cost))
+    }
+}
+
+
+/// a cycle with negative total weight reachable from source
+/// `edges[i]` goes from `vertices[i]` to `vertices[(i+1) % vertices.len()]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct NegativeCycle {
+    vertices: Vec<usize>,
+    edges: Vec<usize>,
+}
+
+impl NegativeCycle {
+    pub fn vertices(&self) -> &[usize] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> &[usize] {
+        &self.edges
+    }
+}
+
+impl fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "negative cycle through vertices: {:?}", self.vertices)
+    }
+}
+
+
+/// single source shortest paths allowing negative weights
+impl<W: Weight, A: Adjacency<W>> Graph<W, A> {
+    /// [Bellman-Ford](https://en.wikipedia.org/wiki/Bellman%E2%80%93Ford_algorithm)
+    /// stop early if no distance changes in a round
+    pub fn bellman_ford(&self, vertex: usize) -> Result<ShortestPaths<W>, NegativeCycle> {
+        let shortest_paths = ShortestPaths::new(vertex, self.adj_list().num_vertices());
+        self.relax_until_converged(shortest_paths)
+    }
+
+    /// distances from a virtual source connected to all vertices with zero weight,
+    /// reweighting edges by `weight + potential[in_vertex] - potential[out_vertex]` makes them non-negative
+    pub(super) fn potential(&self) -> Result<Vec<W>, NegativeCycle> {
+        let shortest_paths = ShortestPaths::with_all_sources(self.adj_list().num_vertices());
+        let shortest_paths = self.relax_until_converged(shortest_paths)?;
+
+        Ok(shortest_paths.distance.into_iter().map(|x| x.unwrap()).collect())
+    }
+
+    fn relax_until_converged(&self, mut shortest_paths: ShortestPaths<W>) -> Result<ShortestPaths<W>, NegativeCycle> {
+        let adj_list = self.adj_list();
+        let num_vertices = adj_list.num_vertices();
+
+        // shortest paths have at most `num_vertices - 1` edges,
+        // any vertex relaxed in the extra round indicates negative cycle
+        let mut relaxed_vertex = None;
+        for _ in 0..num_vertices {
+            relaxed_vertex = None;
+
+            for in_vertex in 1..=num_vertices {
+                let distance = match shortest_paths.distance(in_vertex) {
+                    Some(distance) => distance,
+                    None => continue,
+                };
+
+                for (edge_index, edge) in adj_list.indexed_edge_iterator_of_vertex(in_vertex) {
+                    let new_distance = distance.add_or_panic(edge.weight);
+                    match shortest_paths.distance(edge.out_vertex) {
+                        Some(x) if x <= new_distance => {},
+                        _ => {
+                            shortest_paths.update(edge.out_vertex, new_distance, in_vertex, edge_index);
+                            relaxed_vertex = Some(edge.out_vertex);
+                        }
+                    }
+                }
+            }
+
+            if relaxed_vertex.is_none() {
+                return Ok(shortest_paths);
+            }
+        }
+
+        Err(shortest_paths.find_cycle(relaxed_vertex.unwrap()))
+    }
+
+    /// [SPFA](https://en.wikipedia.org/wiki/Shortest_Path_Faster_Algorithm),
+    /// only vertices whose distance changed are used to relax edges again
+    pub fn spfa(&self, vertex: usize) -> Result<ShortestPaths<W>, NegativeCycle> {
+        let adj_list = self.adj_list();
+        let num_vertices = adj_list.num_vertices();
+        let mut shortest_paths: ShortestPaths<W> = ShortestPaths::new(vertex, num_vertices);
+
+        let mut queue: VecDeque<usize> = VecDeque::with_capacity(num_vertices);
+        let mut in_queue = vec![false; num_vertices + 1];
+        // a vertex can not be relaxed `num_vertices` times without negative cycle
+        let mut relaxed_times = vec![0; num_vertices + 1];
+
+        queue.push_back(vertex);
+        in_queue[vertex] = true;
+
+        while let Some(in_vertex) = queue.pop_front() {
+            in_queue[in_vertex] = false;
+            let distance = shortest_paths.distance(in_vertex).unwrap();
+
+            for (edge_index, edge) in adj_list.indexed_edge_iterator_of_vertex(in_vertex) {
+                let new_distance = distance.add_or_panic(edge.weight);
+                match shortest_paths.distance(edge.out_vertex) {
+                    Some(x) if x <= new_distance => continue,
+                    _ => shortest_paths.update(edge.out_vertex, new_distance, in_vertex, edge_index),
+                }
+
+                relaxed_times[edge.out_vertex] += 1;
+                if relaxed_times[edge.out_vertex] >= num_vertices {
+                    // the negative cycle may not be formed by predecessors yet
+                    if let Some(cycle) = shortest_paths.find_any_cycle() {
+                        return Err(cycle);
+                    }
+                }
+
+                if !in_queue[edge.out_vertex] {
+                    in_queue[edge.out_vertex] = true;
+                    queue.push_back(edge.out_vertex);
+                }
+            }
+        }
+
+        Ok(shortest_paths)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::AdjacencyList;
+
+    const TEST_GRAPH_FILE: &str = "src/graph/examples/test.txt";
+    const SIMPLE_GRAPH_FILE: &str = "src/graph/examples/simple.txt";
+    const POSITIVE_SIMPLE_GRAPH_FILE: &str = "src/graph/examples/positive_simple.txt";
+
+    fn negative_cycle_graph() -> Graph {
+        let mut adj_list = AdjacencyList::new(5, 5);
+        adj_list.add_directed_edge(1, 2, 1);
+        adj_list.add_directed_edge(2, 3, -2);
+        adj_list.add_directed_edge(3, 4, 3);
+        adj_list.add_directed_edge(4, 2, -2);
+        adj_list.add_directed_edge(4, 5, 1);
+        Graph::new(adj_list)
+    }
+
+    /// check cycle is well formed and its weight is negative
+    fn assert_negative_cycle(graph: &Graph, cycle: &NegativeCycle) {
+        let num_vertices = cycle.vertices().len();
+        assert_eq!(cycle.edges().len(), num_vertices);
+
+        let mut weight = 0;
+        for (i, &edge_index) in cycle.edges().iter().enumerate() {
+            let edge = graph.adj_list().edge(edge_index);
+            assert_eq!(edge.in_vertex(), cycle.vertices()[i]);
+            assert_eq!(edge.out_vertex(), cycle.vertices()[(i + 1) % num_vertices]);
+            weight += edge.weight();
+        }
+        assert!(weight < 0);
+    }
+
+    #[test]
+    fn test_bellman_ford() {
+        let graph = Graph::create_from_file(TEST_GRAPH_FILE);
+        let shortest_paths = graph.bellman_ford(1).unwrap();
+
+        assert_eq!(shortest_paths.distances()[1..], [Some(0), Some(4), Some(9), Some(7)]);
+        assert_eq!(shortest_paths.path_to(4).unwrap().vertices(), &[1, 2, 3, 4]);
+
+        let shortest_paths = graph.bellman_ford(3).unwrap();
+        assert_eq!(shortest_paths.distances()[1..], [None, None, Some(0), Some(-2)]);
+    }
+
+    #[test]
+    fn test_bellman_ford_negative_cycle() {
+        let graph = negative_cycle_graph();
+
+        let cycle = graph.bellman_ford(1).unwrap_err();
+        assert_negative_cycle(&graph, &cycle);
+        let mut vertices = cycle.vertices().to_vec();
+        vertices.sort();
+        assert_eq!(vertices, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_bellman_ford_unreachable_negative_cycle() {
+        let mut adj_list = AdjacencyList::new(4, 3);
+        adj_list.add_directed_edge(1, 2, 1);
+        adj_list.add_directed_edge(3, 4, -1);
+        adj_list.add_directed_edge(4, 3, -1);
+        let graph = Graph::new(adj_list);
+
+        let shortest_paths = graph.bellman_ford(1).unwrap();
+        assert_eq!(shortest_paths.distances()[1..], [Some(0), Some(1), None, None]);
+    }
+
+    #[test]
+    fn test_spfa() {
+        let graph = Graph::create_from_file(TEST_GRAPH_FILE);
+        let shortest_paths = graph.spfa(1).unwrap();
+
+        assert_eq!(shortest_paths.distances()[1..], [Some(0), Some(4), Some(9), Some(7)]);
+        assert_eq!(shortest_paths.path_to(4).unwrap().vertices(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_spfa_negative_cycle() {
+        let graph = negative_cycle_graph();
+
+        let cycle = graph.spfa(1).unwrap_err();
+        assert_negative_cycle(&graph, &cycle);
+    }
+
+    #[test]
+    fn test_spfa_same_as_bellman_ford() {
+        let graph: Graph = Graph::create_from_file(POSITIVE_SIMPLE_GRAPH_FILE);
+        assert_eq!(
+            graph.spfa(1).unwrap().distances(),
+            graph.bellman_ford(1).unwrap().distances()
+        );
+
+        let graph = Graph::create_from_file(SIMPLE_GRAPH_FILE);
+        match (graph.spfa(1), graph.bellman_ford(1)) {
+            (Ok(x), Ok(y)) => assert_eq!(x.distances(), y.distances()),
+            (Err(x), Err(y)) => {
+                assert_negative_cycle(&graph, &x);
+                assert_negative_cycle(&graph, &y);
+            },
+            _ => panic!("spfa and bellman ford disagree on negative cycle"),
+        }
+    }
+}
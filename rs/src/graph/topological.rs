@@ -0,0 +1,271 @@
+use super::algorithms::Graph;
+use super::visitor::{Control, Visitor};
+use super::{Adjacency, Edge, Weight};
+
+use std::collections::VecDeque;
+use std::fmt;
+
+/// a directed cycle which makes topological sort impossible
+/// `edges[i]` goes from `vertices[i]` to `vertices[(i+1) % vertices.len()]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CycleFound {
+    vertices: Vec<usize>,
+    edges: Vec<usize>,
+}
+
+impl CycleFound {
+    pub fn vertices(&self) -> &[usize] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> &[usize] {
+        &self.edges
+    }
+}
+
+impl fmt::Display for CycleFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cycle found through vertices: {:?}", self.vertices)
+    }
+}
+
+/// algorithm used by topological sort
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopologicalSortAlgorithm {
+    /// [Kahn](https://en.wikipedia.org/wiki/Topological_sorting#Kahn's_algorithm),
+    /// repeatedly remove vertices without incoming edges
+    Kahn,
+    /// reverse of dfs finishing order
+    Dfs,
+}
+
+
+/// record dfs finishing order and stop at the first back edge
+struct TopologicalVisitor {
+    finished: Vec<usize>,
+    /// index of the tree edge through which each vertex is discovered
+    tree_edge: Vec<Option<usize>>,
+    /// in vertex of the tree edge
+    parent: Vec<usize>,
+    cycle: Option<CycleFound>,
+}
+
+impl<W> Visitor<W> for TopologicalVisitor {
+    fn tree_edge(&mut self, edge_index: usize, edge: &Edge<W>) -> Control {
+        self.tree_edge[edge.out_vertex] = Some(edge_index);
+        self.parent[edge.out_vertex] = edge.in_vertex;
+        Control::Continue
+    }
+
+    fn back_edge(&mut self, edge_index: usize, edge: &Edge<W>) -> Control {
+        // walk up the search tree from in vertex to out vertex of back edge
+        let mut vertices = vec![edge.in_vertex];
+        let mut edges = vec![edge_index];
+        let mut current_vertex = edge.in_vertex;
+        while current_vertex != edge.out_vertex {
+            edges.push(self.tree_edge[current_vertex].unwrap());
+            current_vertex = self.parent[current_vertex];
+            vertices.push(current_vertex);
+        }
+        // now vertices begin with out vertex of back edge and back edge is the last
+        vertices.reverse();
+        edges.reverse();
+
+        self.cycle = Some(CycleFound { vertices, edges });
+        Control::Stop
+    }
+
+    fn finish_vertex(&mut self, vertex: usize) -> Control {
+        self.finished.push(vertex);
+        Control::Continue
+    }
+}
+
+
+/// topological sort and cycle detection for directed graphs
+impl<W: Weight, A: Adjacency<W>> Graph<W, A> {
+    /// order vertices so that every edge goes from an earlier vertex to a later one
+    pub fn topological_sort(&self) -> Result<Vec<usize>, CycleFound> {
+        self.topological_sort_with(TopologicalSortAlgorithm::Kahn)
+    }
+
+    pub fn topological_sort_with(&self, algorithm: TopologicalSortAlgorithm) -> Result<Vec<usize>, CycleFound> {
+        match algorithm {
+            TopologicalSortAlgorithm::Kahn => self.kahn(),
+            TopologicalSortAlgorithm::Dfs => self.dfs_topological_sort(),
+        }
+    }
+
+    /// `None` if graph is acyclic
+    pub fn find_cycle(&self) -> Option<CycleFound> {
+        self.dfs_topological_sort().err()
+    }
+
+    pub fn is_acyclic(&self) -> bool {
+        self.find_cycle().is_none()
+    }
+
+    fn kahn(&self) -> Result<Vec<usize>, CycleFound> {
+        let adj_list = self.adj_list();
+        let num_vertices = adj_list.num_vertices();
+
+        let mut in_degree = vec![0; num_vertices + 1];
+        for vertex in 1..=num_vertices {
+            for edge in adj_list.edge_iterator_of_vertex(vertex) {
+                in_degree[edge.out_vertex] += 1;
+            }
+        }
+
+        let mut queue: VecDeque<usize> = (1..=num_vertices).filter(|&x| in_degree[x] == 0).collect();
+        let mut order = Vec::with_capacity(num_vertices);
+
+        while let Some(vertex) = queue.pop_front() {
+            order.push(vertex);
+            for edge in adj_list.edge_iterator_of_vertex(vertex) {
+                in_degree[edge.out_vertex] -= 1;
+                if in_degree[edge.out_vertex] == 0 {
+                    queue.push_back(edge.out_vertex);
+                }
+            }
+        }
+
+        if order.len() == num_vertices {
+            return Ok(order);
+        }
+
+        // every remaining vertex has an incoming edge from another remaining vertex,
+        // so walking backwards along them must come back to a visited vertex
+        let mut position = vec![None; num_vertices + 1];
+        let mut vertices = Vec::new();
+        let mut edges = Vec::new();
+        let mut current_vertex = (1..=num_vertices).find(|&x| in_degree[x] > 0).unwrap();
+        while position[current_vertex].is_none() {
+            position[current_vertex] = Some(vertices.len());
+            vertices.push(current_vertex);
+
+            let (edge_index, edge) = adj_list
+                .incoming_edge_iterator_of_vertex(current_vertex)
+                .find(|(_, edge)| in_degree[edge.in_vertex] > 0)
+                .unwrap();
+            edges.push(edge_index);
+            current_vertex = edge.in_vertex;
+        }
+
+        // drop the tail walked before entering the cycle,
+        // `edges[i]` goes from `vertices[i+1]` to `vertices[i]`
+        let start = position[current_vertex].unwrap();
+        let mut vertices = vertices.split_off(start);
+        let mut edges = edges.split_off(start);
+        vertices.reverse();
+        edges.reverse();
+        vertices.rotate_right(1);
+
+        Err(CycleFound { vertices, edges })
+    }
+
+    fn dfs_topological_sort(&self) -> Result<Vec<usize>, CycleFound> {
+        let num_vertices = self.adj_list().num_vertices();
+        let mut visitor = TopologicalVisitor {
+            finished: Vec::with_capacity(num_vertices),
+            tree_edge: vec![None; num_vertices + 1],
+            parent: vec![0; num_vertices + 1],
+            cycle: None,
+        };
+
+        self.dfs(&mut visitor);
+
+        match visitor.cycle {
+            Some(cycle) => Err(cycle),
+            None => {
+                visitor.finished.reverse();
+                Ok(visitor.finished)
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::AdjacencyList;
+
+    const TEST_GRAPH_FILE: &str = "src/graph/examples/test.txt";
+    const ALGORITHMS: [TopologicalSortAlgorithm; 2] = [TopologicalSortAlgorithm::Kahn, TopologicalSortAlgorithm::Dfs];
+
+    fn assert_topological_order(graph: &Graph, order: &[usize]) {
+        let num_vertices = graph.adj_list().num_vertices();
+        assert_eq!(order.len(), num_vertices);
+
+        let mut position = vec![0; num_vertices + 1];
+        for (i, &vertex) in order.iter().enumerate() {
+            position[vertex] = i;
+        }
+        for vertex in 1..=num_vertices {
+            for edge in graph.adj_list().edge_iterator_of_vertex(vertex) {
+                assert!(position[edge.in_vertex()] < position[edge.out_vertex()]);
+            }
+        }
+    }
+
+    fn assert_cycle(graph: &Graph, cycle: &CycleFound) {
+        let num_vertices = cycle.vertices().len();
+        assert_eq!(cycle.edges().len(), num_vertices);
+        for (i, &edge_index) in cycle.edges().iter().enumerate() {
+            let edge = graph.adj_list().edge(edge_index);
+            assert_eq!(edge.in_vertex(), cycle.vertices()[i]);
+            assert_eq!(edge.out_vertex(), cycle.vertices()[(i + 1) % num_vertices]);
+        }
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let graph: Graph = Graph::create_from_file(TEST_GRAPH_FILE);
+        for &algorithm in ALGORITHMS.iter() {
+            let order = graph.topological_sort_with(algorithm).unwrap();
+            assert_eq!(order, vec![1, 2, 3, 4]);
+        }
+
+        let mut adj_list = AdjacencyList::new(6, 6);
+        adj_list.add_directed_edge(6, 3, 1);
+        adj_list.add_directed_edge(6, 1, 1);
+        adj_list.add_directed_edge(5, 1, 1);
+        adj_list.add_directed_edge(5, 2, 1);
+        adj_list.add_directed_edge(3, 4, 1);
+        adj_list.add_directed_edge(4, 2, 1);
+        let graph = Graph::new(adj_list);
+        for &algorithm in ALGORITHMS.iter() {
+            let order = graph.topological_sort_with(algorithm).unwrap();
+            assert_topological_order(&graph, &order);
+        }
+        assert!(graph.is_acyclic());
+    }
+
+    #[test]
+    fn test_topological_sort_cycle() {
+        let mut adj_list = AdjacencyList::new(5, 5);
+        adj_list.add_directed_edge(1, 2, 1);
+        adj_list.add_directed_edge(2, 3, 1);
+        adj_list.add_directed_edge(3, 4, 1);
+        adj_list.add_directed_edge(4, 2, 1);
+        adj_list.add_directed_edge(4, 5, 1);
+        let graph = Graph::new(adj_list);
+
+        for &algorithm in ALGORITHMS.iter() {
+            let cycle = graph.topological_sort_with(algorithm).unwrap_err();
+            assert_cycle(&graph, &cycle);
+            let mut vertices = cycle.vertices().to_vec();
+            vertices.sort();
+            assert_eq!(vertices, vec![2, 3, 4]);
+        }
+        assert!(!graph.is_acyclic());
+
+        let mut adj_list = AdjacencyList::new(2, 1);
+        adj_list.add_directed_edge(2, 2, 1);
+        let graph = Graph::new(adj_list);
+        for &algorithm in ALGORITHMS.iter() {
+            let cycle = graph.topological_sort_with(algorithm).unwrap_err();
+            assert_eq!((cycle.vertices(), cycle.edges()), (&[2][..], &[1][..]));
+        }
+    }
+}
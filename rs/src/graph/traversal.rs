@@ -0,0 +1,262 @@
+use super::algorithms::Graph;
+use super::{Adjacency, AdjacencyList, Weight};
+
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::vec;
+
+/// vertex yielded by traversal iterators
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Visit {
+    vertex: usize,
+    /// number of tree edges from root, also the level in bfs
+    depth: usize,
+    /// `None` if vertex is the root of a search tree
+    parent: Option<usize>,
+}
+
+impl Visit {
+    fn root(vertex: usize) -> Visit {
+        Visit {
+            vertex,
+            depth: 0,
+            parent: None,
+        }
+    }
+
+    fn child(&self, vertex: usize) -> Visit {
+        Visit {
+            vertex,
+            depth: self.depth + 1,
+            parent: Some(self.vertex),
+        }
+    }
+
+    pub fn vertex(&self) -> usize {
+        self.vertex
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+}
+
+/// order in which `DfsIter` yields vertices
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DfsOrder {
+    /// vertex is yielded when it is discovered
+    Preorder,
+    /// vertex is yielded when all its descendants have been yielded
+    Postorder,
+    /// reverse of postorder, the whole traversal is done before the first vertex is yielded
+    ReversePostorder,
+}
+
+
+/// lazy breadth first traversal
+pub struct BfsIter<'a, W = i32, A = AdjacencyList<W>> {
+    adj_list: &'a A,
+    _weight: PhantomData<W>,
+    /// start vertices, each one not discovered yet begins a new search tree
+    roots: vec::IntoIter<usize>,
+    discovered: Vec<bool>,
+    queue: VecDeque<Visit>,
+}
+
+impl<'a, W: Weight, A: Adjacency<W>> Iterator for BfsIter<'a, W, A> {
+    type Item = Visit;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(visit) = self.queue.pop_front() {
+                for edge in self.adj_list.edge_iterator_of_vertex(visit.vertex) {
+                    if !self.discovered[edge.out_vertex] {
+                        self.discovered[edge.out_vertex] = true;
+                        self.queue.push_back(visit.child(edge.out_vertex));
+                    }
+                }
+                return Some(visit);
+            }
+
+            let root = self.roots.next()?;
+            if !self.discovered[root] {
+                self.discovered[root] = true;
+                self.queue.push_back(Visit::root(root));
+            }
+        }
+    }
+}
+
+
+/// lazy depth first traversal
+pub struct DfsIter<'a, W: 'a = i32, A: Adjacency<W> + 'a = AdjacencyList<W>> {
+    adj_list: &'a A,
+    order: DfsOrder,
+    /// start vertices, each one not discovered yet begins a new search tree
+    roots: vec::IntoIter<usize>,
+    discovered: Vec<bool>,
+    /// each vertex on stack keeps the iterator over its remaining edges
+    stack: Vec<(Visit, A::IndexedEdgeIter<'a>)>,
+    /// whole postorder used by reverse postorder, vertices are popped from the end
+    postorder: Vec<Visit>,
+}
+
+impl<'a, W: Weight, A: Adjacency<W>> DfsIter<'a, W, A> {
+    fn new(adj_list: &'a A, roots: Vec<usize>, order: DfsOrder) -> DfsIter<'a, W, A> {
+        let mut dfs_iter = DfsIter {
+            adj_list,
+            order,
+            roots: roots.into_iter(),
+            discovered: vec![false; adj_list.num_vertices() + 1],
+            stack: Vec::new(),
+            postorder: Vec::new(),
+        };
+
+        if order == DfsOrder::ReversePostorder {
+            dfs_iter.order = DfsOrder::Postorder;
+            dfs_iter.postorder = dfs_iter.by_ref().collect();
+            dfs_iter.order = DfsOrder::ReversePostorder;
+        }
+
+        dfs_iter
+    }
+}
+
+impl<'a, W: Weight, A: Adjacency<W>> Iterator for DfsIter<'a, W, A> {
+    type Item = Visit;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.order == DfsOrder::ReversePostorder {
+            return self.postorder.pop();
+        }
+
+        loop {
+            let (visit, edge_iterator) = match self.stack.last_mut() {
+                Some((visit, edge_iterator)) => (*visit, edge_iterator),
+                None => {
+                    let root = self.roots.next()?;
+                    if self.discovered[root] {
+                        continue;
+                    }
+                    self.discovered[root] = true;
+                    let visit = Visit::root(root);
+                    self.stack.push((visit, self.adj_list.indexed_edge_iterator_of_vertex(root)));
+                    match self.order {
+                        DfsOrder::Preorder => return Some(visit),
+                        _ => continue,
+                    }
+                }
+            };
+
+            match edge_iterator.next() {
+                Some((_, edge)) => {
+                    if self.discovered[edge.out_vertex] {
+                        continue;
+                    }
+                    self.discovered[edge.out_vertex] = true;
+                    let child = visit.child(edge.out_vertex);
+                    self.stack.push((child, self.adj_list.indexed_edge_iterator_of_vertex(edge.out_vertex)));
+                    if self.order == DfsOrder::Preorder {
+                        return Some(child);
+                    }
+                },
+                None => {
+                    self.stack.pop();
+                    if self.order == DfsOrder::Postorder {
+                        return Some(visit);
+                    }
+                }
+            }
+        }
+    }
+}
+
+
+/// lazy traversal
+impl<W: Weight, A: Adjacency<W>> Graph<W, A> {
+    /// breadth first traversal over vertices reachable from `vertex`
+    pub fn bfs_iter(&self, vertex: usize) -> BfsIter<'_, W, A> {
+        self.bfs_iter_from(vec![vertex])
+    }
+
+    /// breadth first traversal started from `roots` in order,
+    /// a root which has been discovered from previous roots is skipped,
+    /// use `1..=num_vertices` to traverse all vertices
+    pub fn bfs_iter_from<I: IntoIterator<Item = usize>>(&self, roots: I) -> BfsIter<'_, W, A> {
+        BfsIter {
+            adj_list: self.adj_list(),
+            _weight: PhantomData,
+            roots: roots.into_iter().collect::<Vec<usize>>().into_iter(),
+            discovered: vec![false; self.adj_list().num_vertices() + 1],
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// depth first traversal over vertices reachable from `vertex`
+    pub fn dfs_iter(&self, vertex: usize, order: DfsOrder) -> DfsIter<'_, W, A> {
+        self.dfs_iter_from(vec![vertex], order)
+    }
+
+    /// depth first traversal started from `roots` in order,
+    /// a root which has been discovered from previous roots is skipped,
+    /// use `1..=num_vertices` to traverse all vertices
+    pub fn dfs_iter_from<I: IntoIterator<Item = usize>>(&self, roots: I, order: DfsOrder) -> DfsIter<'_, W, A> {
+        DfsIter::new(self.adj_list(), roots.into_iter().collect(), order)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_GRAPH_FILE: &str = "src/graph/examples/test.txt";
+
+    fn vertices<I: Iterator<Item = Visit>>(iter: I) -> Vec<usize> {
+        iter.map(|visit| visit.vertex()).collect()
+    }
+
+    #[test]
+    fn test_bfs_iter() {
+        let graph: Graph = Graph::create_from_file(TEST_GRAPH_FILE);
+
+        let visits: Vec<(usize, usize, Option<usize>)> = graph
+            .bfs_iter(1)
+            .map(|visit| (visit.vertex(), visit.depth(), visit.parent()))
+            .collect();
+        assert_eq!(visits, vec![(1, 0, None), (4, 1, Some(1)), (2, 1, Some(1)), (3, 2, Some(2))]);
+
+        assert_eq!(vertices(graph.bfs_iter(3)), vec![3, 4]);
+        assert_eq!(vertices(graph.bfs_iter_from(vec![3, 1, 4])), vec![3, 4, 1, 2]);
+    }
+
+    #[test]
+    fn test_dfs_iter() {
+        let graph: Graph = Graph::create_from_file(TEST_GRAPH_FILE);
+
+        assert_eq!(vertices(graph.dfs_iter(1, DfsOrder::Preorder)), vec![1, 4, 2, 3]);
+        assert_eq!(vertices(graph.dfs_iter(1, DfsOrder::Postorder)), vec![4, 3, 2, 1]);
+        assert_eq!(vertices(graph.dfs_iter(1, DfsOrder::ReversePostorder)), vec![1, 2, 3, 4]);
+        assert_eq!(vertices(graph.dfs_iter_from(vec![3, 1], DfsOrder::Preorder)), vec![3, 4, 1, 2]);
+
+        let visit = graph.dfs_iter(1, DfsOrder::Postorder).nth(1).unwrap();
+        assert_eq!((visit.vertex(), visit.depth(), visit.parent()), (3, 2, Some(2)));
+    }
+
+    #[test]
+    fn test_traversal_is_lazy() {
+        let graph: Graph = Graph::create_from_file(TEST_GRAPH_FILE);
+
+        let mut dfs_iter = graph.dfs_iter(1, DfsOrder::Preorder);
+        assert_eq!(dfs_iter.next().unwrap().vertex(), 1);
+        assert_eq!(dfs_iter.stack.len(), 1);
+
+        let mut bfs_iter = graph.bfs_iter(1);
+        assert_eq!(bfs_iter.next().unwrap().vertex(), 1);
+        assert_eq!(bfs_iter.queue.len(), 2);
+    }
+}
@@ -181,6 +181,16 @@ pub fn gen_random_integers(m: usize, n: usize, m_range: (usize, usize)) -> Vec<u
     random_integers
 }
 
+/// unweighted undirected graph for tests, every edge is added with `AdjacencyList::add_undirected_edge`
+#[cfg(test)]
+pub(super) fn undirected_graph(num_vertices: usize, edges: &[(usize, usize)]) -> super::algorithms::Graph {
+    let mut adj_list = super::AdjacencyList::new(num_vertices, 2 * edges.len());
+    for &(in_vertex, out_vertex) in edges {
+        adj_list.add_undirected_edge(in_vertex, out_vertex, 1);
+    }
+    super::algorithms::Graph::new(adj_list)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
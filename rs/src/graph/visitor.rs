@@ -0,0 +1,75 @@
+use super::Edge;
+
+/// returned by visitor callbacks to decide whether traversal should go on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    Continue,
+    Stop,
+}
+
+/// return early from a traversal if callback asks to stop
+macro_rules! stop_if_asked {
+    ($control:expr) => {
+        if let Control::Stop = $control {
+            return Control::Stop;
+        }
+    };
+}
+
+/// state of vertex during traversal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Color {
+    /// not discovered yet
+    White,
+    /// discovered but not finished
+    Gray,
+    /// all adjacent edges have been examined
+    Black,
+}
+
+/// callbacks of `Graph::dfs` and `Graph::bfs`, all of them do nothing by default
+/// inspired by [boost graph library](https://www.boost.org/doc/libs/1_73_0/libs/graph/doc/DFSVisitor.html)
+///
+/// note that an undirected edge is stored as two directed edges,
+/// so dfs reports the twin of a tree edge as a back edge
+pub trait Visitor<W = i32> {
+    /// `vertex` is the root of a new search tree
+    fn start_vertex(&mut self, _vertex: usize) -> Control {
+        Control::Continue
+    }
+
+    /// `vertex` is reached for the first time
+    fn discover_vertex(&mut self, _vertex: usize) -> Control {
+        Control::Continue
+    }
+
+    /// called for every outgoing edge of each discovered vertex before it is classified
+    fn examine_edge(&mut self, _edge_index: usize, _edge: &Edge<W>) -> Control {
+        Control::Continue
+    }
+
+    /// edge leads to an undiscovered vertex and becomes part of the search tree
+    fn tree_edge(&mut self, _edge_index: usize, _edge: &Edge<W>) -> Control {
+        Control::Continue
+    }
+
+    /// dfs only, edge leads to an ancestor in the search tree
+    fn back_edge(&mut self, _edge_index: usize, _edge: &Edge<W>) -> Control {
+        Control::Continue
+    }
+
+    /// dfs only, edge leads to a finished vertex
+    fn forward_or_cross_edge(&mut self, _edge_index: usize, _edge: &Edge<W>) -> Control {
+        Control::Continue
+    }
+
+    /// bfs only, edge leads to a vertex which has already been discovered
+    fn non_tree_edge(&mut self, _edge_index: usize, _edge: &Edge<W>) -> Control {
+        Control::Continue
+    }
+
+    /// all outgoing edges of `vertex` have been examined
+    fn finish_vertex(&mut self, _vertex: usize) -> Control {
+        Control::Continue
+    }
+}
@@ -0,0 +1,149 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::num::ParseFloatError;
+use std::str::FromStr;
+
+/// weight of edges and length of paths
+pub trait Weight: Copy + Ord + fmt::Debug + fmt::Display + FromStr {
+    fn zero() -> Self;
+
+    /// `None` if the result can not be represented
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// `None` if the result can not be represented
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// clamp to the bound of the type instead of overflowing
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// used to accumulate lengths of paths, panic rather than overflow silently
+    fn add_or_panic(self, other: Self) -> Self {
+        match self.checked_add(other) {
+            Some(x) => x,
+            None => panic!("weight overflows when adding {} and {}!", self, other),
+        }
+    }
+
+    /// same as `add_or_panic`
+    fn sub_or_panic(self, other: Self) -> Self {
+        match self.checked_sub(other) {
+            Some(x) => x,
+            None => panic!("weight overflows when subtracting {} from {}!", other, self),
+        }
+    }
+}
+
+macro_rules! impl_integer_weight {
+    ($($t:ty),*) => {
+        $(
+            impl Weight for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn saturating_add(self, other: Self) -> Self {
+                    <$t>::saturating_add(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_weight!(i32, i64, u64);
+
+
+/// `f64` ordered by `f64::total_cmp`, so it can be used as weight and heap key,
+/// results which are not finite are regarded as overflow
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TotalF64(pub f64);
+
+impl PartialEq for TotalF64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TotalF64 {}
+
+impl PartialOrd for TotalF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TotalF64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl fmt::Display for TotalF64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for TotalF64 {
+    type Err = ParseFloatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<f64>().map(TotalF64)
+    }
+}
+
+impl Weight for TotalF64 {
+    fn zero() -> Self {
+        TotalF64(0.0)
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(TotalF64(self.0 + other.0)).filter(|x| x.0.is_finite())
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(TotalF64(self.0 - other.0)).filter(|x| x.0.is_finite())
+    }
+
+    fn saturating_add(self, other: Self) -> Self {
+        TotalF64((self.0 + other.0).clamp(f64::MIN, f64::MAX))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_weight() {
+        assert_eq!(Weight::checked_add(i32::MAX, 1), None);
+        assert_eq!(Weight::saturating_add(i64::MAX - 1, 5), i64::MAX);
+        assert_eq!(Weight::checked_sub(0u64, 1), None);
+        assert_eq!(3u64.add_or_panic(4), 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "weight overflows")]
+    fn test_add_or_panic() {
+        i32::MAX.add_or_panic(1);
+    }
+
+    #[test]
+    fn test_total_f64() {
+        let mut weights = [TotalF64(2.5), TotalF64(-1.0), TotalF64(0.0), TotalF64(-0.0)];
+        weights.sort();
+        assert_eq!(weights.iter().map(|x| x.0).collect::<Vec<f64>>(), vec![-1.0, -0.0, 0.0, 2.5]);
+
+        assert_eq!(TotalF64(f64::MAX).checked_add(TotalF64(f64::MAX)), None);
+        assert_eq!(TotalF64(f64::MAX).saturating_add(TotalF64(f64::MAX)), TotalF64(f64::MAX));
+        assert_eq!("1.5".parse::<TotalF64>(), Ok(TotalF64(1.5)));
+    }
+}
@@ -39,6 +39,10 @@ impl<K: Ord, V: Hash + Eq + Copy> HeapMethod<K, V> for BinaryMinHeap<K, V> {
         }
     }
 
+    fn peek(&self) -> Option<&HeapElement<K, V>> {
+        self.data.first()
+    }
+
     fn pop(&mut self) -> Option<HeapElement<K, V>> {
         match self.data.len() {
             0 => None,
@@ -189,6 +193,7 @@ mod tests {
         assert_eq!(heap.get_element(7).unwrap(), &HeapElement::new(4, 7));
         assert_eq!(heap.get_element(8), None);
         
+        assert_eq!(heap.peek().unwrap(), &HeapElement::new(3, 1));
         assert_eq!(heap.pop().unwrap(), HeapElement::new(3, 1));
         assert_eq!(heap.pop().unwrap(), HeapElement::new(4, 7));
         assert_eq!(heap.pop().unwrap(), HeapElement::new(7, 2));
@@ -197,5 +202,6 @@ mod tests {
         assert_eq!(heap.pop().unwrap(), HeapElement::new(16, 6));
         assert_eq!(heap.pop().unwrap(), HeapElement::new(20, 5));
         assert_eq!(heap.pop(), None);
+        assert_eq!(heap.peek(), None);
     }
 }
\ No newline at end of file
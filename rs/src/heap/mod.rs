@@ -23,6 +23,14 @@ impl<K: Ord, V: Hash + Eq + Copy> HeapElement<K, V> {
     pub fn reset_key(&mut self, key: K) {
         self.key = key;
     }
+
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn track_id(&self) -> V {
+        self.track_id
+    }
 }
 
 impl<K: Ord, V: Hash + Eq + Copy> PartialEq for HeapElement<K, V> {
@@ -64,6 +72,8 @@ pub trait HeapMethod<K: Ord, V: Hash + Eq + Copy> {
     fn get_mut_element(&mut self, track_id: V) -> Option<&mut HeapElement<K, V>>;
     // add element to heap
     fn push(&mut self, element: HeapElement<K, V>);
+    // get the top(min/max) element without deleting it
+    fn peek(&self) -> Option<&HeapElement<K, V>>;
     // get and delete the top(min/max) element from heap
     fn pop(&mut self) -> Option<HeapElement<K, V>>;
     // decrease key of existing element
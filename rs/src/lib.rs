@@ -4,3 +4,4 @@
 mod sort;
 mod graph;
 mod heap;
+pub mod union_find;
@@ -0,0 +1,141 @@
+use super::UnionFindMethod;
+
+/// disjoint set with union by size and path compression,
+/// nearly O(1) amortized per operation
+pub struct DisjointSet {
+    /// `parent[i]` of element `i + base`, roots are their own parent
+    parent: Vec<usize>,
+    /// number of elements in the set, only valid for roots
+    size: Vec<usize>,
+    /// the first element, 0 or 1
+    base: usize,
+    num_sets: usize,
+}
+
+impl DisjointSet {
+    /// elements are `0..size`
+    pub fn new(size: usize) -> DisjointSet {
+        DisjointSet::with_base(size, 0)
+    }
+
+    /// elements are `1..=size`, same as index of vertex in `AdjacencyList`
+    pub fn one_based(size: usize) -> DisjointSet {
+        DisjointSet::with_base(size, 1)
+    }
+
+    fn with_base(size: usize, base: usize) -> DisjointSet {
+        DisjointSet {
+            parent: (0..size).collect(),
+            size: vec![1; size],
+            base,
+            num_sets: size,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    fn index(&self, x: usize) -> usize {
+        if x < self.base || x - self.base >= self.parent.len() {
+            panic!("element {} is out of range {}..{}!", x, self.base, self.base + self.parent.len());
+        }
+        x - self.base
+    }
+
+    fn find_root(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // make every element on the path point to root directly
+        let mut current = index;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+
+        root
+    }
+}
+
+impl UnionFindMethod for DisjointSet {
+    fn find(&mut self, x: usize) -> usize {
+        let index = self.index(x);
+        self.find_root(index) + self.base
+    }
+
+    fn union(&mut self, x: usize, y: usize) -> bool {
+        let (x, y) = (self.index(x), self.index(y));
+        let (mut x, mut y) = (self.find_root(x), self.find_root(y));
+        if x == y {
+            return false;
+        }
+
+        // attach the smaller tree to the larger one
+        if self.size[x] < self.size[y] {
+            std::mem::swap(&mut x, &mut y);
+        }
+        self.parent[y] = x;
+        self.size[x] += self.size[y];
+        self.num_sets -= 1;
+
+        true
+    }
+
+    fn num_sets(&self) -> usize {
+        self.num_sets
+    }
+
+    fn set_size(&mut self, x: usize) -> usize {
+        let index = self.index(x);
+        let root = self.find_root(index);
+        self.size[root]
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disjoint_set() {
+        let mut disjoint_set = DisjointSet::new(6);
+        assert_eq!(disjoint_set.num_sets(), 6);
+
+        assert!(disjoint_set.union(0, 1));
+        assert!(disjoint_set.union(2, 3));
+        assert!(disjoint_set.union(1, 3));
+        assert!(!disjoint_set.union(0, 2));
+
+        assert!(disjoint_set.is_same_set(0, 3));
+        assert!(!disjoint_set.is_same_set(0, 4));
+        assert_eq!(disjoint_set.find(2), disjoint_set.find(1));
+        assert_eq!(disjoint_set.set_size(2), 4);
+        assert_eq!(disjoint_set.set_size(5), 1);
+        assert_eq!(disjoint_set.num_sets(), 3);
+    }
+
+    #[test]
+    fn test_one_based_disjoint_set() {
+        let mut disjoint_set = DisjointSet::one_based(3);
+        assert!(disjoint_set.union(1, 3));
+        assert_eq!(disjoint_set.set_size(3), 2);
+        assert!((1..=3).contains(&disjoint_set.find(3)));
+        assert_eq!(disjoint_set.num_sets(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_one_based_disjoint_set_out_of_range() {
+        let mut disjoint_set = DisjointSet::one_based(3);
+        disjoint_set.find(0);
+    }
+}
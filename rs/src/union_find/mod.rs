@@ -0,0 +1,23 @@
+mod disjoint_set;
+mod rollback;
+
+pub use self::disjoint_set::DisjointSet;
+pub use self::rollback::RollbackDisjointSet;
+
+/// more info can be found in [disjoint-set](https://en.wikipedia.org/wiki/Disjoint-set_data_structure)
+/// elements are either `0..size` or `1..=size` depending on how the set is created
+pub trait UnionFindMethod {
+    // representative of the set containing x
+    fn find(&mut self, x: usize) -> usize;
+    // merge sets containing x and y, false if they are already in the same set
+    fn union(&mut self, x: usize, y: usize) -> bool;
+    // number of disjoint sets
+    fn num_sets(&self) -> usize;
+    // number of elements in the set containing x
+    fn set_size(&mut self, x: usize) -> usize;
+
+    // whether x and y are in the same set
+    fn is_same_set(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+}
@@ -0,0 +1,149 @@
+use super::UnionFindMethod;
+
+/// disjoint set whose unions can be undone, used by offline algorithms
+/// union by size without path compression, so every operation is O(log n)
+pub struct RollbackDisjointSet {
+    /// `parent[i]` of element `i + base`, roots are their own parent
+    parent: Vec<usize>,
+    /// number of elements in the set, only valid for roots
+    size: Vec<usize>,
+    /// the first element, 0 or 1
+    base: usize,
+    num_sets: usize,
+    /// roots attached to another root by each successful union
+    history: Vec<usize>,
+}
+
+impl RollbackDisjointSet {
+    /// elements are `0..size`
+    pub fn new(size: usize) -> RollbackDisjointSet {
+        RollbackDisjointSet::with_base(size, 0)
+    }
+
+    /// elements are `1..=size`, same as index of vertex in `AdjacencyList`
+    pub fn one_based(size: usize) -> RollbackDisjointSet {
+        RollbackDisjointSet::with_base(size, 1)
+    }
+
+    fn with_base(size: usize, base: usize) -> RollbackDisjointSet {
+        RollbackDisjointSet {
+            parent: (0..size).collect(),
+            size: vec![1; size],
+            base,
+            num_sets: size,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// current state which can be restored by `rollback`
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// undo all successful unions after `snapshot`
+    pub fn rollback(&mut self, snapshot: usize) {
+        while self.history.len() > snapshot {
+            self.undo();
+        }
+    }
+
+    /// undo the last successful union, false if there is nothing to undo
+    pub fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some(child) => {
+                let root = self.parent[child];
+                self.parent[child] = child;
+                self.size[root] -= self.size[child];
+                self.num_sets += 1;
+                true
+            },
+            None => false,
+        }
+    }
+
+    fn index(&self, x: usize) -> usize {
+        if x < self.base || x - self.base >= self.parent.len() {
+            panic!("element {} is out of range {}..{}!", x, self.base, self.base + self.parent.len());
+        }
+        x - self.base
+    }
+
+    fn find_root(&self, mut index: usize) -> usize {
+        while self.parent[index] != index {
+            index = self.parent[index];
+        }
+        index
+    }
+}
+
+impl UnionFindMethod for RollbackDisjointSet {
+    fn find(&mut self, x: usize) -> usize {
+        self.find_root(self.index(x)) + self.base
+    }
+
+    fn union(&mut self, x: usize, y: usize) -> bool {
+        let (mut x, mut y) = (self.find_root(self.index(x)), self.find_root(self.index(y)));
+        if x == y {
+            return false;
+        }
+
+        if self.size[x] < self.size[y] {
+            std::mem::swap(&mut x, &mut y);
+        }
+        self.parent[y] = x;
+        self.size[x] += self.size[y];
+        self.num_sets -= 1;
+        self.history.push(y);
+
+        true
+    }
+
+    fn num_sets(&self) -> usize {
+        self.num_sets
+    }
+
+    fn set_size(&mut self, x: usize) -> usize {
+        self.size[self.find_root(self.index(x))]
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rollback_disjoint_set() {
+        let mut disjoint_set = RollbackDisjointSet::one_based(5);
+        assert!(disjoint_set.union(1, 2));
+        let snapshot = disjoint_set.snapshot();
+
+        assert!(disjoint_set.union(3, 4));
+        assert!(disjoint_set.union(2, 4));
+        assert!(!disjoint_set.union(1, 3));
+        assert_eq!(disjoint_set.set_size(1), 4);
+        assert_eq!(disjoint_set.num_sets(), 2);
+
+        assert!(disjoint_set.undo());
+        assert!(!disjoint_set.is_same_set(1, 3));
+        assert!(disjoint_set.is_same_set(3, 4));
+
+        disjoint_set.rollback(snapshot);
+        assert!(!disjoint_set.is_same_set(3, 4));
+        assert!(disjoint_set.is_same_set(1, 2));
+        assert_eq!(disjoint_set.set_size(2), 2);
+        assert_eq!(disjoint_set.num_sets(), 4);
+
+        disjoint_set.rollback(0);
+        assert_eq!(disjoint_set.num_sets(), 5);
+        assert!(!disjoint_set.undo());
+    }
+}